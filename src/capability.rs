@@ -5,12 +5,14 @@
 //! - The stdlib capability modules (to return `CapabilityCall` errors)
 //! - The WASM code generator (to emit `env.host_call(cap_id, fn_id, ...)` instructions)
 
+use crate::value::Value;
+
 // ── Capability IDs ───────────────────────────────────────────────────────────
 
 /// HTTP capability (get, post, put, patch, delete).
 pub const CAP_HTTP: u32 = 1;
 
-/// Persistent storage capability (get, set, delete, keys).
+/// Persistent storage capability (get, set, delete, keys, get_all).
 pub const CAP_STORAGE: u32 = 2;
 
 /// Location/GPS capability (current).
@@ -36,6 +38,7 @@ pub const STORAGE_GET: u32 = 1;
 pub const STORAGE_SET: u32 = 2;
 pub const STORAGE_DELETE: u32 = 3;
 pub const STORAGE_KEYS: u32 = 4;
+pub const STORAGE_GET_ALL: u32 = 7;
 
 // ── Function IDs: location ───────────────────────────────────────────────────
 
@@ -49,6 +52,27 @@ pub const NOTIFICATIONS_SEND: u32 = 1;
 
 pub const CREDENTIAL_GET: u32 = 1;
 
+// ── Requests ─────────────────────────────────────────────────────────────────
+
+/// A capability call ready for host dispatch.
+///
+/// Typed view of [`StdlibError::CapabilityCall`](crate::StdlibError::CapabilityCall),
+/// obtained via [`StdlibError::as_capability_request`](crate::StdlibError::as_capability_request)
+/// so hosts don't have to pattern-match the error themselves.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CapabilityRequest {
+    /// Capability module name (e.g. `"http"`).
+    pub module: String,
+    /// Function name within the module (e.g. `"get"`).
+    pub function: String,
+    /// Capability ID for `env.host_call`.
+    pub cap_id: u32,
+    /// Function ID for `env.host_call`.
+    pub fn_id: u32,
+    /// Validated call arguments.
+    pub args: Vec<Value>,
+}
+
 // ── Lookup ───────────────────────────────────────────────────────────────────
 
 /// Resolve a capability module name + function name to `(cap_id, fn_id)`.
@@ -74,6 +98,7 @@ pub fn resolve_ids(module: &str, function: &str) -> Option<(u32, u32)> {
         ("storage", "set") => Some((CAP_STORAGE, STORAGE_SET)),
         ("storage", "delete") => Some((CAP_STORAGE, STORAGE_DELETE)),
         ("storage", "keys") => Some((CAP_STORAGE, STORAGE_KEYS)),
+        ("storage", "get_all") => Some((CAP_STORAGE, STORAGE_GET_ALL)),
 
         ("location", "current") => Some((CAP_LOCATION, LOCATION_CURRENT)),
 
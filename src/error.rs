@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::capability::CapabilityRequest;
+
 /// Errors that can occur when calling stdlib functions.
 #[derive(Debug, Clone, Error)]
 pub enum StdlibError {
@@ -45,6 +47,42 @@ pub enum StdlibError {
 }
 
 impl StdlibError {
+    /// Stable machine-readable code for this error kind.
+    ///
+    /// Codes are part of the public API contract — hosts may match on them,
+    /// so existing codes must never change.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::WrongArgCount { .. } => "wrong_arg_count",
+            Self::TypeMismatch { .. } => "type_mismatch",
+            Self::AssertionFailed { .. } => "assertion_failed",
+            Self::UnknownFunction { .. } => "unknown_function",
+            Self::RuntimeError(_) => "runtime_error",
+            Self::CapabilityCall { .. } => "capability_call",
+        }
+    }
+
+    /// If this is a `CapabilityCall`, return it as a [`CapabilityRequest`]
+    /// for host dispatch; `None` for every other error.
+    pub fn as_capability_request(&self) -> Option<CapabilityRequest> {
+        match self {
+            Self::CapabilityCall {
+                module,
+                function,
+                cap_id,
+                fn_id,
+                args,
+            } => Some(CapabilityRequest {
+                module: module.clone(),
+                function: function.clone(),
+                cap_id: *cap_id,
+                fn_id: *fn_id,
+                args: args.clone(),
+            }),
+            _ => None,
+        }
+    }
+
     /// Create a `WrongArgCount` error.
     pub fn wrong_args(function: &str, expected: usize, got: usize) -> Self {
         Self::WrongArgCount {
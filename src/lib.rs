@@ -17,13 +17,17 @@
 //! | `convert` | 5 | Type conversion (fallible and infallible) |
 //! | `json` | 2 | JSON parse/stringify |
 //! | `timer` | 4 | Recurring and one-shot timer scheduling |
+//! | `encoding` | 2 | Hex encoding of byte lists |
+//! | `validate` | 6 | Pragmatic input checks (email, URL, ranges) |
+//! | `path` | 5 | `/`-separated path manipulation |
+//! | `csv` | 2 | RFC 4180 record parsing and formatting |
 //!
 //! # Capability Modules
 //!
 //! | Module | Functions | cap_id | Description |
 //! |--------|-----------|--------|-------------|
 //! | `http` | 5 | 1 | HTTP requests (get, post, put, patch, delete) |
-//! | `storage` | 4 | 2 | Persistent key-value storage (get, set, delete, keys) |
+//! | `storage` | 5 | 2 | Persistent key-value storage (get, set, delete, keys, get_all) |
 //! | `location` | 1 | 3 | GPS/location access (current) |
 //! | `notifications` | 1 | 4 | Push notifications (send) |
 
@@ -33,7 +37,12 @@ mod value;
 
 pub mod capability;
 pub mod modules;
+pub mod registry;
 
 pub use error::StdlibError;
-pub use module::StdlibModule;
-pub use value::{ResultValue, StdlibFn, Value};
+pub use module::{Arity, StdlibModule};
+pub use registry::StdlibRegistry;
+pub use value::{RecordBuilder, ResultValue, StdlibFn, Value};
+
+#[cfg(feature = "threadsafe")]
+pub use value::SharedValue;
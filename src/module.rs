@@ -1,6 +1,28 @@
 use crate::error::StdlibError;
 use crate::value::Value;
 
+/// Number of arguments a stdlib function accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Arity {
+    /// Exactly this many arguments.
+    Exact(usize),
+    /// Between `min` and `max` arguments inclusive (optional trailing arguments).
+    Range(usize, usize),
+    /// This many arguments or more (variadic).
+    AtLeast(usize),
+}
+
+impl Arity {
+    /// Whether a call with `count` arguments satisfies this arity.
+    pub fn accepts(&self, count: usize) -> bool {
+        match *self {
+            Arity::Exact(n) => count == n,
+            Arity::Range(min, max) => (min..=max).contains(&count),
+            Arity::AtLeast(min) => count >= min,
+        }
+    }
+}
+
 /// Trait implemented by each PEPL stdlib module.
 ///
 /// Every module (core, math, string, list, etc.) implements this trait.
@@ -26,4 +48,34 @@ pub trait StdlibModule {
     /// Returns `Err(StdlibError::WrongArgCount)` if argument count is wrong.
     /// Returns `Err(StdlibError::TypeMismatch)` if an argument has the wrong type.
     fn call(&self, function: &str, args: Vec<Value>) -> Result<Value, StdlibError>;
+
+    /// Declared argument count of `function`, used to validate calls without
+    /// executing them (see `StdlibRegistry::validate_call`).
+    ///
+    /// Returns `None` for unknown functions. The default returns `None` for
+    /// everything, so modules without signature metadata are never rejected
+    /// on arity alone.
+    fn arity(&self, _function: &str) -> Option<Arity> {
+        None
+    }
+
+    /// Call a function, surfacing recoverable errors as a PEPL `Err` value.
+    ///
+    /// For REPL-style hosts that want failures as data. A successful result
+    /// becomes `Ok(value)`; `WrongArgCount`, `TypeMismatch`, `UnknownFunction`
+    /// and `RuntimeError` become `Err(message)` using the error's display
+    /// text. Two errors are still returned as Rust `Err`:
+    ///
+    /// - `CapabilityCall` — the call must be routed to the host, not
+    ///   reported as a failure.
+    /// - `AssertionFailed` — a failed `core.assert` is a deliberate trap.
+    fn try_call(&self, function: &str, args: Vec<Value>) -> Result<Value, StdlibError> {
+        match self.call(function, args) {
+            Ok(value) => Ok(value.ok()),
+            Err(
+                err @ (StdlibError::CapabilityCall { .. } | StdlibError::AssertionFailed { .. }),
+            ) => Err(err),
+            Err(err) => Ok(Value::String(err.to_string()).err()),
+        }
+    }
 }
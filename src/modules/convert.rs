@@ -1,9 +1,11 @@
 //! `convert` stdlib module — type conversion utilities.
 //!
-//! Functions: to_string, to_number, parse_int, parse_float, to_bool.
+//! Functions: to_string, to_number, parse_int, parse_float, to_bool, to_i32,
+//! format_number, to_number_strict, to_bool_strict, parse_percent, to_percent,
+//! coerce, bytes_to_human, infer.
 
 use crate::error::StdlibError;
-use crate::module::StdlibModule;
+use crate::module::{Arity, StdlibModule};
 use crate::value::Value;
 
 /// The `convert` stdlib module.
@@ -29,7 +31,20 @@ impl StdlibModule for ConvertModule {
     fn has_function(&self, function: &str) -> bool {
         matches!(
             function,
-            "to_string" | "to_number" | "parse_int" | "parse_float" | "to_bool"
+            "to_string"
+                | "to_number"
+                | "parse_int"
+                | "parse_float"
+                | "to_bool"
+                | "to_i32"
+                | "format_number"
+                | "to_number_strict"
+                | "to_bool_strict"
+                | "parse_percent"
+                | "to_percent"
+                | "coerce"
+                | "bytes_to_human"
+                | "infer"
         )
     }
 
@@ -40,9 +55,29 @@ impl StdlibModule for ConvertModule {
             "parse_int" => self.parse_int(args),
             "parse_float" => self.parse_float(args),
             "to_bool" => self.to_bool(args),
+            "to_i32" => self.to_i32(args),
+            "format_number" => self.format_number(args),
+            "to_number_strict" => self.to_number_strict(args),
+            "to_bool_strict" => self.to_bool_strict(args),
+            "parse_percent" => self.parse_percent(args),
+            "to_percent" => self.to_percent(args),
+            "coerce" => self.coerce(args),
+            "bytes_to_human" => self.bytes_to_human(args),
+            "infer" => self.infer(args),
             _ => Err(StdlibError::unknown_function("convert", function)),
         }
     }
+
+    fn arity(&self, function: &str) -> Option<Arity> {
+        Some(match function {
+            "to_string" | "to_number" | "parse_int" | "parse_float" | "to_bool" | "to_i32"
+            | "to_number_strict" | "to_bool_strict" | "parse_percent" | "bytes_to_human"
+            | "infer" => Arity::Exact(1),
+            "format_number" => Arity::Exact(3),
+            "to_percent" | "coerce" => Arity::Exact(2),
+            _ => return None,
+        })
+    }
 }
 
 impl ConvertModule {
@@ -115,10 +150,333 @@ impl ConvertModule {
         }
         Ok(Value::Bool(args[0].is_truthy()))
     }
+
+    /// convert.to_bool_strict(value) → Result<bool, string>
+    /// Ok only for an actual bool; no truthiness coercion.
+    fn to_bool_strict(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 1 {
+            return Err(StdlibError::wrong_args(
+                "convert.to_bool_strict",
+                1,
+                args.len(),
+            ));
+        }
+        match &args[0] {
+            Value::Bool(b) => Ok(Value::Bool(*b).ok()),
+            other => Ok(Value::String(format!("expected bool, got {}", other.type_name())).err()),
+        }
+    }
+
+    /// convert.to_i32(value) → Result<number, string>
+    /// Truncates toward zero, then returns Err if the result falls outside the
+    /// 32-bit signed integer range (no silent wraparound).
+    fn to_i32(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 1 {
+            return Err(StdlibError::wrong_args("convert.to_i32", 1, args.len()));
+        }
+        let n = extract_number("convert.to_i32", &args[0], 1)?;
+        let truncated = n.trunc();
+        if !truncated.is_finite() || truncated < i32::MIN as f64 || truncated > i32::MAX as f64 {
+            return Ok(Value::String(format!("{} is outside the i32 range", args[0])).err());
+        }
+        Ok(Value::Number(truncated).ok())
+    }
+
+    /// convert.format_number(n, decimals, thousands_sep) → string
+    /// Fixed-decimal rendering with optional digit grouping, e.g.
+    /// `format_number(1234.5, 2, ",")` → `"1,234.50"`. An empty separator
+    /// disables grouping. `decimals` must be a whole number from 0 to 100.
+    /// Traps on NaN and ±infinity.
+    fn format_number(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 3 {
+            return Err(StdlibError::wrong_args(
+                "convert.format_number",
+                3,
+                args.len(),
+            ));
+        }
+        let n = extract_finite("convert.format_number", &args[0], 1)?;
+        let decimals = extract_decimals("convert.format_number", &args[1], 2)?;
+        let sep = extract_string("convert.format_number", &args[2], 3)?;
+
+        let fixed = format!("{:.*}", decimals, n.abs());
+        let (int_part, frac_part) = match fixed.split_once('.') {
+            Some((i, f)) => (i, Some(f)),
+            None => (fixed.as_str(), None),
+        };
+
+        let mut result = String::new();
+        // Don't render "-0.00" when the value rounds to zero
+        if n < 0.0 && fixed.chars().any(|c| matches!(c, '1'..='9')) {
+            result.push('-');
+        }
+        result.push_str(&group_digits(int_part, sep));
+        if let Some(frac) = frac_part {
+            result.push('.');
+            result.push_str(frac);
+        }
+        Ok(Value::String(result))
+    }
+
+    /// convert.to_number_strict(s) → Result<number, string>
+    /// Trims surrounding whitespace, then accepts only plain decimal syntax:
+    /// an optional `-`, digits, an optional `.digits` fraction and an optional
+    /// `e`/`E` exponent (`"1.5e3"` → 1500). Rejects `inf`/`nan`/`infinity`,
+    /// a leading `+`, bare `.5`/`5.`, and values that overflow to infinity.
+    fn to_number_strict(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 1 {
+            return Err(StdlibError::wrong_args(
+                "convert.to_number_strict",
+                1,
+                args.len(),
+            ));
+        }
+        let s = extract_string("convert.to_number_strict", &args[0], 1)?;
+        let trimmed = s.trim();
+        if !is_strict_decimal(trimmed) {
+            return Ok(Value::String(format!("'{}' is not a plain decimal number", s)).err());
+        }
+        match trimmed.parse::<f64>() {
+            Ok(n) if n.is_finite() => Ok(Value::Number(n).ok()),
+            _ => Ok(Value::String(format!("'{}' is out of number range", s)).err()),
+        }
+    }
+
+    /// convert.parse_percent(s) → Result<number, string>
+    /// Parses a percent string into a fraction: `"45%"` → 0.45. The body must
+    /// be a plain decimal (as for `to_number_strict`) followed by `%`;
+    /// whitespace around the number and the sign is ignored.
+    fn parse_percent(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 1 {
+            return Err(StdlibError::wrong_args(
+                "convert.parse_percent",
+                1,
+                args.len(),
+            ));
+        }
+        let s = extract_string("convert.parse_percent", &args[0], 1)?;
+        let Some(body) = s.trim().strip_suffix('%') else {
+            return Ok(Value::String(format!("'{}' is missing a '%' sign", s)).err());
+        };
+        let body = body.trim_end();
+        if !is_strict_decimal(body) {
+            return Ok(Value::String(format!("'{}' is not a valid percentage", s)).err());
+        }
+        match body.parse::<f64>() {
+            Ok(n) if n.is_finite() => Ok(Value::Number(n / 100.0).ok()),
+            _ => Ok(Value::String(format!("'{}' is out of number range", s)).err()),
+        }
+    }
+
+    /// convert.to_percent(n, decimals) → string
+    /// Formats a fraction as a percent string with `decimals` fixed places:
+    /// `to_percent(0.456, 1)` → `"45.6%"`. `decimals` must be a whole number
+    /// from 0 to 100, as for `format_number`. Traps on NaN and ±infinity,
+    /// including a percentage that overflows.
+    fn to_percent(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 2 {
+            return Err(StdlibError::wrong_args("convert.to_percent", 2, args.len()));
+        }
+        let n = extract_finite("convert.to_percent", &args[0], 1)?;
+        let decimals = extract_decimals("convert.to_percent", &args[1], 2)?;
+        let percent = n * 100.0;
+        if !percent.is_finite() {
+            return Err(StdlibError::RuntimeError(
+                "convert.to_percent: percentage would overflow to infinity".to_string(),
+            ));
+        }
+        let fixed = format!("{:.*}", decimals, percent.abs());
+        // Don't render "-0.0%" when the value rounds to zero
+        let sign = if n < 0.0 && fixed.chars().any(|c| matches!(c, '1'..='9')) {
+            "-"
+        } else {
+            ""
+        };
+        Ok(Value::String(format!("{}{}%", sign, fixed)))
+    }
+
+    /// convert.coerce(value, target_type) → Result<any, string>
+    /// Best-effort conversion to `"number"`, `"string"` or `"bool"`, using the
+    /// same rules as `to_number`, `to_string` and `to_bool` respectively.
+    /// Err for any other target or when the conversion is impossible.
+    fn coerce(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 2 {
+            return Err(StdlibError::wrong_args("convert.coerce", 2, args.len()));
+        }
+        let target = extract_string("convert.coerce", &args[1], 2)?;
+        let value = args[0].clone();
+        match target {
+            "number" => self.to_number(vec![value]),
+            "string" => Ok(self.to_string_fn(vec![value])?.ok()),
+            "bool" => Ok(self.to_bool(vec![value])?.ok()),
+            other => Ok(Value::String(format!("unsupported coercion target '{}'", other)).err()),
+        }
+    }
+
+    /// convert.bytes_to_human(bytes) → string
+    /// Formats a byte count with binary (1024) multiples, labelled with the
+    /// familiar B/KB/MB/GB/TB units: `1536` → `"1.5 KB"`. Plain bytes have
+    /// no decimal (`"512 B"`); larger units use one. TB is the largest unit.
+    /// `bytes` must be a non-negative whole number.
+    fn bytes_to_human(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 1 {
+            return Err(StdlibError::wrong_args(
+                "convert.bytes_to_human",
+                1,
+                args.len(),
+            ));
+        }
+        let bytes = extract_number("convert.bytes_to_human", &args[0], 1)?;
+        if bytes < 0.0 || bytes.fract() != 0.0 || !bytes.is_finite() {
+            return Err(StdlibError::RuntimeError(
+                "convert.bytes_to_human: bytes must be a non-negative integer".to_string(),
+            ));
+        }
+        const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+        if bytes < 1024.0 {
+            return Ok(Value::String(format!("{} B", bytes)));
+        }
+        let mut size = bytes / 1024.0;
+        let mut unit = 0;
+        // Promote anything that would print as "1024.0" to the next unit
+        while size >= 1023.95 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+        Ok(Value::String(format!("{:.1} {}", size, UNITS[unit])))
+    }
+
+    /// convert.infer(s) → any
+    /// Reads a text cell as its most specific value. Ignoring surrounding
+    /// whitespace, the first match wins:
+    /// 1. `"null"` or `"nil"` → nil
+    /// 2. `"true"` / `"false"` → bool
+    /// 3. a plain decimal (as for `to_number_strict`) → number
+    /// 4. otherwise → `s` unchanged (including `""` and text like `"42px"`)
+    ///
+    /// Matching is case-sensitive, so `"True"` stays a string. Never fails.
+    fn infer(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 1 {
+            return Err(StdlibError::wrong_args("convert.infer", 1, args.len()));
+        }
+        let s = extract_string("convert.infer", &args[0], 1)?;
+        let trimmed = s.trim();
+        let inferred = match trimmed {
+            "null" | "nil" => Value::Nil,
+            "true" => Value::Bool(true),
+            "false" => Value::Bool(false),
+            _ => match trimmed.parse::<f64>() {
+                Ok(n) if is_strict_decimal(trimmed) && n.is_finite() => Value::Number(n),
+                _ => Value::String(s.to_string()),
+            },
+        };
+        Ok(inferred)
+    }
+}
+
+/// Matches `-?digits(.digits)?([eE][+-]?digits)?`.
+fn is_strict_decimal(s: &str) -> bool {
+    fn digits(bytes: &[u8], mut i: usize) -> usize {
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        i
+    }
+
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    if bytes.first() == Some(&b'-') {
+        i += 1;
+    }
+    let end = digits(bytes, i);
+    if end == i {
+        return false;
+    }
+    i = end;
+    if bytes.get(i) == Some(&b'.') {
+        let end = digits(bytes, i + 1);
+        if end == i + 1 {
+            return false;
+        }
+        i = end;
+    }
+    if matches!(bytes.get(i), Some(b'e' | b'E')) {
+        i += 1;
+        if matches!(bytes.get(i), Some(b'+' | b'-')) {
+            i += 1;
+        }
+        let end = digits(bytes, i);
+        if end == i {
+            return false;
+        }
+        i = end;
+    }
+    i == bytes.len()
+}
+
+/// Insert `sep` between every group of three digits, counting from the right.
+fn group_digits(digits: &str, sep: &str) -> String {
+    if sep.is_empty() {
+        return digits.to_string();
+    }
+    let len = digits.len();
+    let mut out = String::with_capacity(len + len / 3 * sep.len());
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (len - i).is_multiple_of(3) {
+            out.push_str(sep);
+        }
+        out.push(c);
+    }
+    out
 }
 
 // ── Helpers ──────────────────────────────────────────────────────────────────
 
+fn extract_number(func: &str, val: &Value, pos: usize) -> Result<f64, StdlibError> {
+    match val {
+        Value::Number(n) => Ok(*n),
+        _ => Err(StdlibError::type_mismatch(
+            func,
+            pos,
+            "number",
+            val.type_name(),
+        )),
+    }
+}
+
+/// Extract a number that must be finite; NaN and ±infinity trap, matching
+/// math's `nan_guard`.
+fn extract_finite(func: &str, val: &Value, pos: usize) -> Result<f64, StdlibError> {
+    let n = extract_number(func, val, pos)?;
+    if !n.is_finite() {
+        return Err(StdlibError::RuntimeError(format!(
+            "{func}: argument {pos} must be a finite number, got {n}"
+        )));
+    }
+    Ok(n)
+}
+
+/// Largest `decimals` accepted by `format_number` and `to_percent` (the same
+/// limit as JavaScript's `toFixed`). Rust's formatter panics on precisions
+/// above `u16::MAX`, so the argument must be bounded before `format!`.
+const MAX_DECIMALS: f64 = 100.0;
+
+/// Extract a `decimals` argument: a whole number in `0..=MAX_DECIMALS`.
+fn extract_decimals(func: &str, val: &Value, pos: usize) -> Result<usize, StdlibError> {
+    let decimals = extract_number(func, val, pos)?;
+    if decimals < 0.0 || decimals.fract() != 0.0 || !decimals.is_finite() {
+        return Err(StdlibError::RuntimeError(format!(
+            "{func}: decimals must be a non-negative integer"
+        )));
+    }
+    if decimals > MAX_DECIMALS {
+        return Err(StdlibError::RuntimeError(format!(
+            "{func}: decimals must be at most {MAX_DECIMALS}"
+        )));
+    }
+    Ok(decimals as usize)
+}
+
 fn extract_string<'a>(func: &str, val: &'a Value, pos: usize) -> Result<&'a str, StdlibError> {
     match val {
         Value::String(s) => Ok(s),
@@ -1,4 +1,4 @@
-//! The `core` module — 4 functions.
+//! The `core` module — 8 functions.
 //!
 //! | Function | Signature | Description |
 //! |----------|-----------|-------------|
@@ -6,17 +6,39 @@
 //! | `core.assert` | `(condition: bool, message?: string) -> nil` | Trap if false |
 //! | `core.type_of` | `(value: any) -> string` | Returns type name |
 //! | `core.capability` | `(name: string) -> bool` | Check capability availability |
+//! | `core.assert_type` | `(value: any, expected: string) -> nil` | Trap if type differs |
+//! | `core.trace` | `(label: string, value: any) -> any` | Labeled debug trace, returns value |
+//! | `core.mark_start` | `(label: string) -> nil` | Host timing marker (start) |
+//! | `core.mark_end` | `(label: string) -> nil` | Host timing marker (end) |
+
+use std::collections::BTreeSet;
 
 use crate::error::StdlibError;
-use crate::module::StdlibModule;
+use crate::module::{Arity, StdlibModule};
 use crate::value::Value;
 
 /// The `core` stdlib module.
-pub struct CoreModule;
+///
+/// **Breaking change:** this used to be a unit struct. It now holds the host's
+/// capability set in a private field, so the bare `CoreModule` value no longer
+/// compiles. Construct it with [`CoreModule::new`] (or `Default`) or
+/// [`CoreModule::with_capabilities`].
+pub struct CoreModule {
+    capabilities: BTreeSet<String>,
+}
 
 impl CoreModule {
     pub fn new() -> Self {
-        Self
+        Self::with_capabilities(&[])
+    }
+
+    /// Create a module whose `core.capability` reports `true` for each of
+    /// `capabilities` (e.g. `["http"]`) and `false` for everything else.
+    /// The set is fixed at construction, so answers never change per instance.
+    pub fn with_capabilities(capabilities: &[&str]) -> Self {
+        Self {
+            capabilities: capabilities.iter().map(|c| c.to_string()).collect(),
+        }
     }
 }
 
@@ -32,7 +54,17 @@ impl StdlibModule for CoreModule {
     }
 
     fn has_function(&self, function: &str) -> bool {
-        matches!(function, "log" | "assert" | "type_of" | "capability")
+        matches!(
+            function,
+            "log"
+                | "assert"
+                | "type_of"
+                | "capability"
+                | "assert_type"
+                | "trace"
+                | "mark_start"
+                | "mark_end"
+        )
     }
 
     fn call(&self, function: &str, args: Vec<Value>) -> Result<Value, StdlibError> {
@@ -41,9 +73,22 @@ impl StdlibModule for CoreModule {
             "assert" => self.assert(args),
             "type_of" => self.type_of(args),
             "capability" => self.capability(args),
+            "assert_type" => self.assert_type(args),
+            "trace" => self.trace(args),
+            "mark_start" => self.mark("core.mark_start", args),
+            "mark_end" => self.mark("core.mark_end", args),
             _ => Err(StdlibError::unknown_function("core", function)),
         }
     }
+
+    fn arity(&self, function: &str) -> Option<Arity> {
+        Some(match function {
+            "log" | "type_of" | "capability" | "mark_start" | "mark_end" => Arity::Exact(1),
+            "assert" => Arity::Range(1, 2),
+            "assert_type" | "trace" => Arity::Exact(2),
+            _ => return None,
+        })
+    }
 }
 
 impl CoreModule {
@@ -117,16 +162,14 @@ impl CoreModule {
     /// `core.capability(name: string) -> bool`
     ///
     /// Returns whether a declared optional capability is available at runtime.
-    /// In Phase 0, no capabilities are declared, so this always returns `false`.
+    /// Only capabilities passed to [`CoreModule::with_capabilities`] are
+    /// available; the default module reports `false` for every name.
     fn capability(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
         if args.len() != 1 {
             return Err(StdlibError::wrong_args("core.capability", 1, args.len()));
         }
         match &args[0] {
-            Value::String(_) => {
-                // Phase 0: no capabilities are ever available
-                Ok(Value::Bool(false))
-            }
+            Value::String(name) => Ok(Value::Bool(self.capabilities.contains(name))),
             other => Err(StdlibError::type_mismatch(
                 "core.capability",
                 1,
@@ -135,4 +178,88 @@ impl CoreModule {
             )),
         }
     }
+
+    /// `core.assert_type(value: any, expected: string) -> nil`
+    ///
+    /// Traps with `TypeMismatch` unless `value` has the expected type. The
+    /// expected name matches if it equals `core.type_of(value)`; named
+    /// records additionally match `"record"` and sum variants match
+    /// `"variant"`, so callers can check either the declared or generic kind.
+    fn assert_type(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 2 {
+            return Err(StdlibError::wrong_args("core.assert_type", 2, args.len()));
+        }
+        let expected = match &args[1] {
+            Value::String(s) => s.as_str(),
+            other => {
+                return Err(StdlibError::type_mismatch(
+                    "core.assert_type",
+                    2,
+                    "string",
+                    other.type_name(),
+                ));
+            }
+        };
+
+        let value = &args[0];
+        let generic_kind = match value {
+            Value::Record { .. } => Some("record"),
+            Value::SumVariant { .. } => Some("variant"),
+            _ => None,
+        };
+        if value.type_name() == expected || generic_kind == Some(expected) {
+            Ok(Value::Nil)
+        } else {
+            Err(StdlibError::type_mismatch(
+                "core.assert_type",
+                1,
+                expected,
+                value.type_name(),
+            ))
+        }
+    }
+
+    /// `core.trace(label: string, value: any) -> any`
+    ///
+    /// Labeled debug trace that returns `value` unchanged, so it can wrap any
+    /// sub-expression. Like `core.log`, the stdlib side is a pass-through:
+    /// a dev/test host observes traces by intercepting `core.trace` calls at
+    /// the call boundary, where both the label and value are in the arguments.
+    fn trace(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 2 {
+            return Err(StdlibError::wrong_args("core.trace", 2, args.len()));
+        }
+        if !matches!(args[0], Value::String(_)) {
+            return Err(StdlibError::type_mismatch(
+                "core.trace",
+                1,
+                "string",
+                args[0].type_name(),
+            ));
+        }
+        Ok(args.into_iter().nth(1).unwrap_or(Value::Nil))
+    }
+
+    /// `core.mark_start(label: string) -> nil` /
+    /// `core.mark_end(label: string) -> nil`
+    ///
+    /// Profiling markers. A host times the span between matching
+    /// `mark_start`/`mark_end` labels by intercepting these calls at the call
+    /// boundary, the same channel as `core.log` and `core.trace`. The stdlib
+    /// side only validates the label and returns `Nil`, so pure execution
+    /// stays deterministic.
+    fn mark(&self, fn_name: &str, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 1 {
+            return Err(StdlibError::wrong_args(fn_name, 1, args.len()));
+        }
+        if !matches!(args[0], Value::String(_)) {
+            return Err(StdlibError::type_mismatch(
+                fn_name,
+                1,
+                "string",
+                args[0].type_name(),
+            ));
+        }
+        Ok(Value::Nil)
+    }
 }
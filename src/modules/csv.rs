@@ -0,0 +1,186 @@
+//! `csv` stdlib module — single-line CSV parsing and formatting.
+//!
+//! Functions: parse_line, format_line.
+//! Follows RFC 4180: fields are separated by `,`, and a field wrapped in `"`
+//! may contain commas, line breaks and doubled quotes (`""` → `"`). Works one
+//! record at a time; splitting a document into records is up to the caller.
+
+use crate::error::StdlibError;
+use crate::module::{Arity, StdlibModule};
+use crate::value::Value;
+
+/// The `csv` stdlib module.
+pub struct CsvModule;
+
+impl CsvModule {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for CsvModule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StdlibModule for CsvModule {
+    fn name(&self) -> &'static str {
+        "csv"
+    }
+
+    fn has_function(&self, function: &str) -> bool {
+        matches!(function, "parse_line" | "format_line")
+    }
+
+    fn call(&self, function: &str, args: Vec<Value>) -> Result<Value, StdlibError> {
+        match function {
+            "parse_line" => self.parse_line(args),
+            "format_line" => self.format_line(args),
+            _ => Err(StdlibError::unknown_function("csv", function)),
+        }
+    }
+
+    fn arity(&self, function: &str) -> Option<Arity> {
+        Some(match function {
+            "parse_line" | "format_line" => Arity::Exact(1),
+            _ => return None,
+        })
+    }
+}
+
+impl CsvModule {
+    /// csv.parse_line(s) → list<string>
+    /// Splits one record into its fields: `a,"b,c",""` → `["a", "b,c", ""]`.
+    /// A single trailing `\n` or `\r\n` is ignored, and `""` is one empty
+    /// field. A `"` inside an unquoted field is kept literally. Traps on an
+    /// unterminated quoted field or text after a closing quote.
+    fn parse_line(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 1 {
+            return Err(StdlibError::wrong_args("csv.parse_line", 1, args.len()));
+        }
+        let line = match &args[0] {
+            Value::String(s) => s.as_str(),
+            other => {
+                return Err(StdlibError::type_mismatch(
+                    "csv.parse_line",
+                    1,
+                    "string",
+                    other.type_name(),
+                ))
+            }
+        };
+        let line = match line.strip_suffix('\n') {
+            Some(rest) => rest.strip_suffix('\r').unwrap_or(rest),
+            None => line,
+        };
+        let fields = parse_fields(line)?;
+        Ok(Value::List(fields.into_iter().map(Value::String).collect()))
+    }
+
+    /// csv.format_line(fields) → string
+    /// Joins fields with `,`, quoting any field that contains a comma, quote
+    /// or line break and doubling its quotes. No trailing newline is added.
+    /// An empty list formats as `""`, which parses back as one empty field.
+    fn format_line(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 1 {
+            return Err(StdlibError::wrong_args("csv.format_line", 1, args.len()));
+        }
+        let fields = extract_string_list("csv.format_line", &args[0], 1)?;
+        let formatted: Vec<String> = fields.into_iter().map(format_field).collect();
+        Ok(Value::String(formatted.join(",")))
+    }
+}
+
+// ── Helpers ──────────────────────────────────────────────────────────────────
+
+/// Parse one record (without its line terminator) into fields.
+fn parse_fields(line: &str) -> Result<Vec<String>, StdlibError> {
+    let mut fields = Vec::new();
+    let mut chars = line.chars().peekable();
+    loop {
+        let mut field = String::new();
+        if chars.peek() == Some(&'"') {
+            chars.next();
+            loop {
+                match chars.next() {
+                    Some('"') if chars.peek() == Some(&'"') => {
+                        chars.next();
+                        field.push('"');
+                    }
+                    Some('"') => break,
+                    Some(c) => field.push(c),
+                    None => {
+                        return Err(StdlibError::RuntimeError(format!(
+                            "csv.parse_line: unterminated quoted field {}",
+                            fields.len()
+                        )))
+                    }
+                }
+            }
+            match chars.next() {
+                Some(',') => fields.push(field),
+                None => {
+                    fields.push(field);
+                    return Ok(fields);
+                }
+                Some(c) => {
+                    return Err(StdlibError::RuntimeError(format!(
+                        "csv.parse_line: unexpected '{c}' after closing quote in field {}",
+                        fields.len()
+                    )))
+                }
+            }
+        } else {
+            loop {
+                match chars.next() {
+                    Some(',') => break,
+                    Some(c) => field.push(c),
+                    None => {
+                        fields.push(field);
+                        return Ok(fields);
+                    }
+                }
+            }
+            fields.push(field);
+        }
+    }
+}
+
+/// Quote a field if it contains a separator, quote or line break.
+fn format_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn extract_string_list<'a>(
+    func: &str,
+    val: &'a Value,
+    pos: usize,
+) -> Result<Vec<&'a str>, StdlibError> {
+    let items = match val {
+        Value::List(items) => items,
+        _ => {
+            return Err(StdlibError::type_mismatch(
+                func,
+                pos,
+                "list",
+                val.type_name(),
+            ))
+        }
+    };
+    items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| match item {
+            Value::String(s) => Ok(s.as_str()),
+            other => Err(StdlibError::RuntimeError(format!(
+                "{func}: element {i} must be a string, got {}",
+                other.type_name()
+            ))),
+        })
+        .collect()
+}
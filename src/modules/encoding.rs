@@ -0,0 +1,137 @@
+//! `encoding` stdlib module — byte/text encodings.
+//!
+//! Functions: to_hex, from_hex.
+//! Bytes are represented as `list<number>` with each element a whole number
+//! in 0..=255.
+
+use crate::error::StdlibError;
+use crate::module::{Arity, StdlibModule};
+use crate::value::Value;
+
+/// The `encoding` stdlib module.
+pub struct EncodingModule;
+
+impl EncodingModule {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for EncodingModule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StdlibModule for EncodingModule {
+    fn name(&self) -> &'static str {
+        "encoding"
+    }
+
+    fn has_function(&self, function: &str) -> bool {
+        matches!(function, "to_hex" | "from_hex")
+    }
+
+    fn call(&self, function: &str, args: Vec<Value>) -> Result<Value, StdlibError> {
+        match function {
+            "to_hex" => self.encode_hex(args),
+            "from_hex" => self.decode_hex(args),
+            _ => Err(StdlibError::unknown_function("encoding", function)),
+        }
+    }
+
+    fn arity(&self, function: &str) -> Option<Arity> {
+        Some(match function {
+            "to_hex" | "from_hex" => Arity::Exact(1),
+            _ => return None,
+        })
+    }
+}
+
+impl EncodingModule {
+    /// encoding.to_hex(bytes) → string
+    /// Lowercase hex, two digits per byte. Traps if any element is not a
+    /// whole number in 0..=255.
+    fn encode_hex(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 1 {
+            return Err(StdlibError::wrong_args("encoding.to_hex", 1, args.len()));
+        }
+        let bytes = extract_bytes("encoding.to_hex", &args[0], 1)?;
+        let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+        Ok(Value::String(hex))
+    }
+
+    /// encoding.from_hex(s) → Result<list<number>, string>
+    /// Accepts upper- and lowercase digits. Returns Err on odd length or
+    /// any non-hex character.
+    fn decode_hex(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 1 {
+            return Err(StdlibError::wrong_args("encoding.from_hex", 1, args.len()));
+        }
+        let s = extract_string("encoding.from_hex", &args[0], 1)?;
+        if s.len() % 2 != 0 {
+            return Ok(Value::String(format!("hex string has odd length {}", s.len())).err());
+        }
+
+        let mut bytes = Vec::with_capacity(s.len() / 2);
+        for (i, pair) in s.as_bytes().chunks(2).enumerate() {
+            match (hex_digit(pair[0]), hex_digit(pair[1])) {
+                (Some(hi), Some(lo)) => bytes.push(Value::Number(f64::from(hi << 4 | lo))),
+                _ => {
+                    return Ok(
+                        Value::String(format!("invalid hex digit at position {}", i * 2)).err(),
+                    )
+                }
+            }
+        }
+        Ok(Value::List(bytes).ok())
+    }
+}
+
+// ── Helpers ──────────────────────────────────────────────────────────────────
+
+fn hex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+fn extract_bytes(func: &str, val: &Value, pos: usize) -> Result<Vec<u8>, StdlibError> {
+    let items = match val {
+        Value::List(items) => items,
+        _ => {
+            return Err(StdlibError::type_mismatch(
+                func,
+                pos,
+                "list",
+                val.type_name(),
+            ))
+        }
+    };
+    items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| match item {
+            Value::Number(n) if n.fract() == 0.0 && (0.0..=255.0).contains(n) => Ok(*n as u8),
+            _ => Err(StdlibError::RuntimeError(format!(
+                "{}: element {} is not a byte (0-255): {}",
+                func, i, item
+            ))),
+        })
+        .collect()
+}
+
+fn extract_string<'a>(func: &str, val: &'a Value, pos: usize) -> Result<&'a str, StdlibError> {
+    match val {
+        Value::String(s) => Ok(s),
+        _ => Err(StdlibError::type_mismatch(
+            func,
+            pos,
+            "string",
+            val.type_name(),
+        )),
+    }
+}
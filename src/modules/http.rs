@@ -6,6 +6,14 @@
 //! validates arguments and returns `CapabilityCall` errors to signal the
 //! caller to route the call to the host.
 //!
+//! # Request bodies
+//!
+//! `post`, `put` and `patch` accept the body as a raw string, which is passed
+//! through unchanged, or as a record, list, number or bool, which is
+//! serialized to a JSON string before it is placed in the `CapabilityCall`
+//! args. A serialized body implies a `content-type: application/json` hint:
+//! hosts should send that header unless the options set one explicitly.
+//!
 //! # Cap ID / Fn ID Mapping
 //!
 //! | fn_id | Function |
@@ -18,7 +26,8 @@
 
 use crate::capability::{CAP_HTTP, HTTP_DELETE, HTTP_GET, HTTP_PATCH, HTTP_POST, HTTP_PUT};
 use crate::error::StdlibError;
-use crate::module::StdlibModule;
+use crate::module::{Arity, StdlibModule};
+use crate::modules::json::value_to_json;
 use crate::value::Value;
 
 /// The `http` capability module.
@@ -55,6 +64,14 @@ impl StdlibModule for HttpModule {
             _ => Err(StdlibError::unknown_function("http", function)),
         }
     }
+
+    fn arity(&self, function: &str) -> Option<Arity> {
+        Some(match function {
+            "get" | "delete" => Arity::Range(1, 2),
+            "post" | "put" | "patch" => Arity::Range(2, 3),
+            _ => return None,
+        })
+    }
 }
 
 impl HttpModule {
@@ -72,46 +89,49 @@ impl HttpModule {
         ))
     }
 
-    /// `http.post(url: string, body: string, options?: HttpOptions) -> Result<HttpResponse, HttpError>`
+    /// `http.post(url: string, body: string|record|list|number|bool, options?: HttpOptions) -> Result<HttpResponse, HttpError>`
     ///
-    /// Validates: 2 or 3 args, first two must be strings.
+    /// Validates: 2 or 3 args, first must be a string. Non-string bodies are
+    /// serialized to JSON (see [module docs](self)).
     /// Returns `CapabilityCall` with cap_id=1, fn_id=2.
-    fn post(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+    fn post(&self, mut args: Vec<Value>) -> Result<Value, StdlibError> {
         if args.len() < 2 || args.len() > 3 {
             return Err(StdlibError::wrong_args("http.post", 2, args.len()));
         }
         validate_string("http.post", &args[0], 1)?;
-        validate_string("http.post", &args[1], 2)?;
+        encode_body("http.post", &mut args[1])?;
         Err(StdlibError::capability_call(
             "http", "post", CAP_HTTP, HTTP_POST, args,
         ))
     }
 
-    /// `http.put(url: string, body: string, options?: HttpOptions) -> Result<HttpResponse, HttpError>`
+    /// `http.put(url: string, body: string|record|list|number|bool, options?: HttpOptions) -> Result<HttpResponse, HttpError>`
     ///
-    /// Validates: 2 or 3 args, first two must be strings.
+    /// Validates: 2 or 3 args, first must be a string. Non-string bodies are
+    /// serialized to JSON (see [module docs](self)).
     /// Returns `CapabilityCall` with cap_id=1, fn_id=3.
-    fn put(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+    fn put(&self, mut args: Vec<Value>) -> Result<Value, StdlibError> {
         if args.len() < 2 || args.len() > 3 {
             return Err(StdlibError::wrong_args("http.put", 2, args.len()));
         }
         validate_string("http.put", &args[0], 1)?;
-        validate_string("http.put", &args[1], 2)?;
+        encode_body("http.put", &mut args[1])?;
         Err(StdlibError::capability_call(
             "http", "put", CAP_HTTP, HTTP_PUT, args,
         ))
     }
 
-    /// `http.patch(url: string, body: string, options?: HttpOptions) -> Result<HttpResponse, HttpError>`
+    /// `http.patch(url: string, body: string|record|list|number|bool, options?: HttpOptions) -> Result<HttpResponse, HttpError>`
     ///
-    /// Validates: 2 or 3 args, first two must be strings.
+    /// Validates: 2 or 3 args, first must be a string. Non-string bodies are
+    /// serialized to JSON (see [module docs](self)).
     /// Returns `CapabilityCall` with cap_id=1, fn_id=4.
-    fn patch(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+    fn patch(&self, mut args: Vec<Value>) -> Result<Value, StdlibError> {
         if args.len() < 2 || args.len() > 3 {
             return Err(StdlibError::wrong_args("http.patch", 2, args.len()));
         }
         validate_string("http.patch", &args[0], 1)?;
-        validate_string("http.patch", &args[1], 2)?;
+        encode_body("http.patch", &mut args[1])?;
         Err(StdlibError::capability_call(
             "http", "patch", CAP_HTTP, HTTP_PATCH, args,
         ))
@@ -138,6 +158,25 @@ impl HttpModule {
 
 // ── Helpers ──────────────────────────────────────────────────────────────────
 
+/// Leave a string body as-is; serialize a record, list, number or bool body
+/// to a JSON string in place.
+fn encode_body(func: &str, body: &mut Value) -> Result<(), StdlibError> {
+    match body {
+        Value::String(_) => Ok(()),
+        Value::Record { .. } | Value::List(_) | Value::Number(_) | Value::Bool(_) => {
+            let json = value_to_json(body).to_string();
+            *body = Value::String(json);
+            Ok(())
+        }
+        _ => Err(StdlibError::type_mismatch(
+            func,
+            2,
+            "string, record, list, number or bool",
+            body.type_name(),
+        )),
+    }
+}
+
 fn validate_string(func: &str, val: &Value, pos: usize) -> Result<(), StdlibError> {
     match val {
         Value::String(_) => Ok(()),
@@ -1,12 +1,13 @@
 //! `json` stdlib module — JSON parsing and serialization.
 //!
-//! Functions: parse, stringify.
+//! Functions: parse, parse_array, parse_object, stringify, stringify_canonical,
+//! pluck, diff, flatten, unflatten.
 //! Max parse depth: 32 (prevents stack overflow on deeply nested JSON).
 
 use std::collections::BTreeMap;
 
 use crate::error::StdlibError;
-use crate::module::StdlibModule;
+use crate::module::{Arity, StdlibModule};
 use crate::value::{ResultValue, Value};
 
 /// Maximum allowed nesting depth when parsing JSON.
@@ -33,16 +34,48 @@ impl StdlibModule for JsonModule {
     }
 
     fn has_function(&self, function: &str) -> bool {
-        matches!(function, "parse" | "stringify")
+        matches!(
+            function,
+            "parse"
+                | "parse_array"
+                | "parse_object"
+                | "stringify"
+                | "stringify_canonical"
+                | "pluck"
+                | "diff"
+                | "flatten"
+                | "unflatten"
+        )
     }
 
     fn call(&self, function: &str, args: Vec<Value>) -> Result<Value, StdlibError> {
         match function {
             "parse" => self.parse(args),
+            "parse_array" => self.parse_array(args),
+            "parse_object" => self.parse_object(args),
             "stringify" => self.stringify(args),
+            "stringify_canonical" => self.stringify_canonical(args),
+            "pluck" => self.pluck(args),
+            "diff" => self.diff(args),
+            "flatten" => self.flatten(args),
+            "unflatten" => self.unflatten(args),
             _ => Err(StdlibError::unknown_function("json", function)),
         }
     }
+
+    fn arity(&self, function: &str) -> Option<Arity> {
+        Some(match function {
+            "parse"
+            | "parse_array"
+            | "parse_object"
+            | "stringify"
+            | "stringify_canonical"
+            | "flatten"
+            | "unflatten" => Arity::Exact(1),
+            "pluck" | "diff" => Arity::Exact(2),
+            _ => return None,
+        })
+    }
 }
 
 impl JsonModule {
@@ -63,6 +96,53 @@ impl JsonModule {
         }
     }
 
+    /// json.parse_array(s) → Result<list, string>
+    /// Like `json.parse`, but returns Err unless the top-level value is an array.
+    fn parse_array(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        self.parse_expecting("json.parse_array", "array", args)
+    }
+
+    /// json.parse_object(s) → Result<record, string>
+    /// Like `json.parse`, but returns Err unless the top-level value is an object.
+    fn parse_object(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        self.parse_expecting("json.parse_object", "object", args)
+    }
+
+    /// Shared body of `parse_array`/`parse_object`: parse, then check the
+    /// top-level JSON kind before converting.
+    fn parse_expecting(
+        &self,
+        func: &str,
+        kind: &str,
+        args: Vec<Value>,
+    ) -> Result<Value, StdlibError> {
+        if args.len() != 1 {
+            return Err(StdlibError::wrong_args(func, 1, args.len()));
+        }
+        let s = extract_string(func, &args[0], 1)?;
+
+        let json_val = match serde_json::from_str::<serde_json::Value>(s) {
+            Ok(v) => v,
+            Err(e) => return Ok(Value::String(format!("JSON parse error: {}", e)).err()),
+        };
+        let matches_kind = match kind {
+            "array" => json_val.is_array(),
+            _ => json_val.is_object(),
+        };
+        if !matches_kind {
+            return Ok(Value::String(format!(
+                "expected JSON {}, got {}",
+                kind,
+                json_kind(&json_val)
+            ))
+            .err());
+        }
+        match json_to_value(&json_val, 0) {
+            Ok(v) => Ok(v.ok()),
+            Err(msg) => Ok(Value::String(msg).err()),
+        }
+    }
+
     /// json.stringify(value) → string
     /// Converts a PEPL Value to a JSON string.
     fn stringify(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
@@ -74,6 +154,146 @@ impl JsonModule {
             serde_json::to_string(&json_val).unwrap_or_else(|_| "null".to_string()),
         ))
     }
+
+    /// json.stringify_canonical(value) → string
+    /// Compact JSON with object keys sorted at every nesting level, so equal
+    /// values always produce byte-identical output (e.g. for hashing or
+    /// signatures). `-0.0` is written as `0.0`, since the two compare equal.
+    fn stringify_canonical(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 1 {
+            return Err(StdlibError::wrong_args(
+                "json.stringify_canonical",
+                1,
+                args.len(),
+            ));
+        }
+        let mut out = String::new();
+        write_canonical_json(&value_to_json(&args[0]), &mut out);
+        Ok(Value::String(out))
+    }
+
+    /// json.pluck(value, key) → list
+    /// Extracts `key` from each record in a list, using nil where a record
+    /// lacks the key. Every element must be a record.
+    fn pluck(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 2 {
+            return Err(StdlibError::wrong_args("json.pluck", 2, args.len()));
+        }
+        let items = match &args[0] {
+            Value::List(items) => items,
+            other => {
+                return Err(StdlibError::type_mismatch(
+                    "json.pluck",
+                    1,
+                    "list",
+                    other.type_name(),
+                ))
+            }
+        };
+        let key = extract_string("json.pluck", &args[1], 2)?;
+
+        let mut result = Vec::with_capacity(items.len());
+        for (i, item) in items.iter().enumerate() {
+            match item {
+                Value::Record { fields, .. } => {
+                    result.push(fields.get(key).cloned().unwrap_or(Value::Nil))
+                }
+                other => {
+                    return Err(StdlibError::RuntimeError(format!(
+                        "json.pluck: element {} is {}, expected record",
+                        i,
+                        other.type_name()
+                    )))
+                }
+            }
+        }
+        Ok(Value::List(result))
+    }
+
+    /// json.diff(old, new) → record
+    /// Shallow (top-level) diff of two parsed values, always shaped
+    /// `{equal, added, removed, changed}`. When both are records, `added` and
+    /// `removed` hold the keys present on only one side (with their values)
+    /// and `changed` maps each differing shared key to `{old, new}`. Nested
+    /// values are compared whole, not diffed recursively. For any other pair
+    /// (scalars, lists, mismatched kinds) only `equal` is meaningful and the
+    /// other three fields are empty records.
+    fn diff(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 2 {
+            return Err(StdlibError::wrong_args("json.diff", 2, args.len()));
+        }
+        let mut added = BTreeMap::new();
+        let mut removed = BTreeMap::new();
+        let mut changed = BTreeMap::new();
+        if let (Value::Record { fields: old, .. }, Value::Record { fields: new, .. }) =
+            (&args[0], &args[1])
+        {
+            for (key, old_val) in old {
+                match new.get(key) {
+                    None => {
+                        removed.insert(key.clone(), old_val.clone());
+                    }
+                    Some(new_val) if new_val != old_val => {
+                        let change =
+                            Value::record_of(&[("old", old_val.clone()), ("new", new_val.clone())]);
+                        changed.insert(key.clone(), change);
+                    }
+                    Some(_) => {}
+                }
+            }
+            for (key, new_val) in new {
+                if !old.contains_key(key) {
+                    added.insert(key.clone(), new_val.clone());
+                }
+            }
+        }
+        Ok(Value::record_of(&[
+            ("equal", Value::Bool(args[0] == args[1])),
+            ("added", Value::record(added)),
+            ("removed", Value::record(removed)),
+            ("changed", Value::record(changed)),
+        ]))
+    }
+
+    /// json.flatten(value) → record
+    /// Flattens nested records and lists into a single-level record with
+    /// dotted keys: `{user: {city: "Oslo"}, tags: ["a"]}` →
+    /// `{"user.city": "Oslo", "tags.0": "a"}`. Empty records and lists are
+    /// kept as leaf values. Traps past the max depth of 32.
+    fn flatten(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 1 {
+            return Err(StdlibError::wrong_args("json.flatten", 1, args.len()));
+        }
+        let fields = extract_record("json.flatten", &args[0], 1)?;
+        let mut flat = BTreeMap::new();
+        for (key, val) in fields {
+            flatten_into(key.clone(), val, 1, &mut flat)?;
+        }
+        Ok(Value::record(flat))
+    }
+
+    /// json.unflatten(flat) → record
+    /// Inverse of `json.flatten`: splits each key on `.` and rebuilds nested
+    /// records. A level whose keys are exactly `0..n` becomes a list. Traps
+    /// when paths conflict (e.g. `"a"` and `"a.b"` both present) or nest
+    /// deeper than 32 levels.
+    fn unflatten(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 1 {
+            return Err(StdlibError::wrong_args("json.unflatten", 1, args.len()));
+        }
+        let flat = extract_record("json.unflatten", &args[0], 1)?;
+        let mut root = BTreeMap::new();
+        for (key, val) in flat {
+            let segments: Vec<&str> = key.split('.').collect();
+            if segments.len() > MAX_DEPTH {
+                return Err(StdlibError::RuntimeError(format!(
+                    "json.unflatten: key '{key}' exceeds maximum depth of {MAX_DEPTH}"
+                )));
+            }
+            insert_path(&mut root, &segments, val.clone(), key)?;
+        }
+        Ok(node_to_value(PathNode::Branch(root)))
+    }
 }
 
 // ── JSON ↔ Value conversion ────────────────────────────────────────────────
@@ -109,8 +329,39 @@ fn json_to_value(json: &serde_json::Value, depth: usize) -> Result<Value, String
     }
 }
 
+/// Host-side conversion from parsed JSON, with the same depth limit as
+/// `json.parse`. Objects become anonymous records; numbers become `f64`.
+#[cfg(feature = "json")]
+impl TryFrom<serde_json::Value> for Value {
+    type Error = StdlibError;
+
+    fn try_from(json: serde_json::Value) -> Result<Self, Self::Error> {
+        json_to_value(&json, 0).map_err(StdlibError::RuntimeError)
+    }
+}
+
+/// Host-side conversion to JSON, using the same encoding as `json.stringify`.
+#[cfg(feature = "json")]
+impl From<&Value> for serde_json::Value {
+    fn from(value: &Value) -> Self {
+        value_to_json(value)
+    }
+}
+
+/// JSON type name of a parsed value, for error messages.
+fn json_kind(json: &serde_json::Value) -> &'static str {
+    match json {
+        serde_json::Value::Null => "null",
+        serde_json::Value::Bool(_) => "boolean",
+        serde_json::Value::Number(_) => "number",
+        serde_json::Value::String(_) => "string",
+        serde_json::Value::Array(_) => "array",
+        serde_json::Value::Object(_) => "object",
+    }
+}
+
 /// Convert a PEPL Value to a serde_json::Value for serialization.
-fn value_to_json(value: &Value) -> serde_json::Value {
+pub(crate) fn value_to_json(value: &Value) -> serde_json::Value {
     match value {
         Value::Nil => serde_json::Value::Null,
         Value::Bool(b) => serde_json::Value::Bool(*b),
@@ -175,6 +426,128 @@ fn value_to_json(value: &Value) -> serde_json::Value {
     }
 }
 
+/// Write `json` compactly, folding `-0.0` to `0.0`. Object keys come out
+/// sorted because `serde_json::Map` is a `BTreeMap` (no `preserve_order`).
+fn write_canonical_json(json: &serde_json::Value, out: &mut String) {
+    match json {
+        serde_json::Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical_json(item, out);
+            }
+            out.push(']');
+        }
+        serde_json::Value::Object(obj) => {
+            out.push('{');
+            for (i, (key, val)) in obj.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&serde_json::Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical_json(val, out);
+            }
+            out.push('}');
+        }
+        serde_json::Value::Number(n) if n.as_f64() == Some(0.0) => out.push_str("0.0"),
+        scalar => out.push_str(&scalar.to_string()),
+    }
+}
+
+/// Insert `value` into `flat` under `prefix`, recursing into non-empty
+/// records and lists.
+fn flatten_into(
+    prefix: String,
+    value: &Value,
+    depth: usize,
+    flat: &mut BTreeMap<String, Value>,
+) -> Result<(), StdlibError> {
+    if depth > MAX_DEPTH {
+        return Err(StdlibError::RuntimeError(format!(
+            "json.flatten: nesting exceeds maximum depth of {MAX_DEPTH}"
+        )));
+    }
+    match value {
+        Value::Record { fields, .. } if !fields.is_empty() => {
+            for (key, val) in fields {
+                flatten_into(format!("{prefix}.{key}"), val, depth + 1, flat)?;
+            }
+        }
+        Value::List(items) if !items.is_empty() => {
+            for (i, item) in items.iter().enumerate() {
+                flatten_into(format!("{prefix}.{i}"), item, depth + 1, flat)?;
+            }
+        }
+        _ => {
+            flat.insert(prefix, value.clone());
+        }
+    }
+    Ok(())
+}
+
+/// Intermediate tree for `json.unflatten`.
+enum PathNode {
+    Leaf(Value),
+    Branch(BTreeMap<String, PathNode>),
+}
+
+fn insert_path(
+    branch: &mut BTreeMap<String, PathNode>,
+    segments: &[&str],
+    value: Value,
+    key: &str,
+) -> Result<(), StdlibError> {
+    let conflict = || {
+        StdlibError::RuntimeError(format!(
+            "json.unflatten: key '{key}' conflicts with another key's path"
+        ))
+    };
+    let (first, rest) = segments.split_first().expect("split yields a segment");
+    if rest.is_empty() {
+        if branch.contains_key(*first) {
+            return Err(conflict());
+        }
+        branch.insert(first.to_string(), PathNode::Leaf(value));
+        return Ok(());
+    }
+    let child = branch
+        .entry(first.to_string())
+        .or_insert_with(|| PathNode::Branch(BTreeMap::new()));
+    match child {
+        PathNode::Branch(inner) => insert_path(inner, rest, value, key),
+        PathNode::Leaf(_) => Err(conflict()),
+    }
+}
+
+/// A branch whose keys are exactly `"0"..n` becomes a list; any other
+/// branch becomes a record.
+fn node_to_value(node: PathNode) -> Value {
+    match node {
+        PathNode::Leaf(value) => value,
+        PathNode::Branch(children) => {
+            let is_list = (0..children.len()).all(|i| children.contains_key(&i.to_string()));
+            if is_list && !children.is_empty() {
+                let mut items: Vec<(usize, Value)> = children
+                    .into_iter()
+                    .map(|(k, child)| (k.parse().unwrap_or(0), node_to_value(child)))
+                    .collect();
+                items.sort_by_key(|(i, _)| *i);
+                Value::List(items.into_iter().map(|(_, v)| v).collect())
+            } else {
+                Value::record(
+                    children
+                        .into_iter()
+                        .map(|(k, child)| (k, node_to_value(child)))
+                        .collect(),
+                )
+            }
+        }
+    }
+}
+
 // ── Helpers ──────────────────────────────────────────────────────────────────
 
 fn extract_string<'a>(func: &str, val: &'a Value, pos: usize) -> Result<&'a str, StdlibError> {
@@ -188,3 +561,19 @@ fn extract_string<'a>(func: &str, val: &'a Value, pos: usize) -> Result<&'a str,
         )),
     }
 }
+
+fn extract_record<'a>(
+    func: &str,
+    val: &'a Value,
+    pos: usize,
+) -> Result<&'a BTreeMap<String, Value>, StdlibError> {
+    match val {
+        Value::Record { fields, .. } => Ok(fields),
+        _ => Err(StdlibError::type_mismatch(
+            func,
+            pos,
+            "record",
+            val.type_name(),
+        )),
+    }
+}
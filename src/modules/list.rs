@@ -1,14 +1,17 @@
-//! The `list` module — 32 functions (31 spec + 5 extensions + `some` alias).
+//! The `list` module — 66 functions, plus the `set` (→ `update`) and `some`
+//! (→ `any`) aliases.
 //!
 //! All operations are **immutable** — they return new lists, never mutate.
 //!
-//! ## Construction (4)
+//! ## Construction (6)
 //! | Function       | Signature                                    |
 //! |----------------|----------------------------------------------|
 //! | `list.empty`   | `() -> list`                                 |
 //! | `list.of`      | `(...items) -> list` (variadic)               |
 //! | `list.repeat`  | `(value, count: number) -> list`             |
 //! | `list.range`   | `(start: number, end: number) -> list`       |
+//! | `list.repeat_with` | `(count: number, f: fn(index) -> any) -> list` |
+//! | `list.range_map_filter` | `(start: number, end: number, f: fn(number) -> any, pred: fn(any) -> bool) -> list` |
 //!
 //! ## Access (5)
 //! | Function         | Signature                                  |
@@ -19,7 +22,7 @@
 //! | `list.last`      | `(items: list) -> any\|nil`                |
 //! | `list.index_of`  | `(items: list, value) -> number`           |
 //!
-//! ## Modification (10)
+//! ## Modification (18)
 //! | Function         | Signature                                            |
 //! |------------------|------------------------------------------------------|
 //! | `list.append`    | `(items: list, value) -> list`                       |
@@ -27,36 +30,72 @@
 //! | `list.insert`    | `(items: list, index: number, value) -> list`        |
 //! | `list.remove`    | `(items: list, index: number) -> list`               |
 //! | `list.update`    | `(items: list, index: number, value) -> list`        |
+//! | `list.swap`      | `(items: list, i: number, j: number) -> list`        |
 //! | `list.slice`     | `(items: list, start: number, end: number) -> list`  |
 //! | `list.concat`    | `(a: list, b: list) -> list`                         |
+//! | `list.concat_all` | `(lists: list<list>) -> list`                       |
+//! | `list.interleave` | `(a: list, b: list) -> list`                        |
 //! | `list.reverse`   | `(items: list) -> list`                              |
 //! | `list.flatten`   | `(items: list) -> list`                              |
 //! | `list.unique`    | `(items: list) -> list`                              |
+//! | `list.dedup_consecutive` | `(items: list) -> list`                      |
+//! | `list.without`   | `(items: list, value) -> list`                       |
+//! | `list.replace`   | `(items: list, old, new) -> list`                    |
+//! | `list.shuffle`   | `(items: list, seed: number) -> list`                |
+//! | `list.splice`    | `(items: list, start: number, delete_count: number, insert: list) -> list` |
 //!
-//! ## Higher-Order (9)
+//! ## Higher-Order (17)
 //! | Function           | Signature                                               |
 //! |--------------------|---------------------------------------------------------|
 //! | `list.map`         | `(items: list, f: fn(any) -> any) -> list`              |
 //! | `list.filter`      | `(items: list, pred: fn(any) -> bool) -> list`          |
 //! | `list.reduce`      | `(items: list, init, f: fn(acc, item) -> acc) -> any`   |
+//! | `list.reduce_while` | `(items: list, init, step: fn(acc, item) -> record) -> any` |
 //! | `list.find`        | `(items: list, pred: fn(any) -> bool) -> any\|nil`      |
 //! | `list.find_index`  | `(items: list, pred: fn(any) -> bool) -> number`        |
+//! | `list.index_of_by` | `(items: list, target, key_fn: fn(any) -> any) -> number` |
 //! | `list.every`       | `(items: list, pred: fn(any) -> bool) -> bool`          |
 //! | `list.any`         | `(items: list, pred: fn(any) -> bool) -> bool`          |
-//! | `list.sort`        | `(items: list, cmp: fn(a, b) -> number) -> list`        |
+//! | `list.sort`        | `(items: list, cmp?: fn(a, b) -> number) -> list`       |
+//! | `list.is_sorted`   | `(items: list, cmp?: fn(a, b) -> number) -> bool`       |
 //! | `list.count`       | `(items: list, pred: fn(any) -> bool) -> number`        |
+//! | `list.group_consecutive` | `(items: list, key_fn: fn(any) -> any) -> list<record>` |
+//! | `list.sum_by`      | `(items: list, key_fn: fn(any) -> number) -> number`    |
+//! | `list.average_by`  | `(items: list, key_fn: fn(any) -> number) -> number`    |
+//! | `list.positions`   | `(items: list, pred: fn(any) -> bool) -> list<number>`  |
+//! | `list.update_where` | `(items: list, pred: fn(any) -> bool, f: fn(any) -> any) -> list` |
 //!
-//! ## Query (4) — also non-higher-order
+//! ## Query (20) — also non-higher-order
 //! | Function         | Signature                                  |
 //! |------------------|--------------------------------------------|
 //! | `list.contains`  | `(items: list, value) -> bool`             |
 //! | `list.zip`       | `(a: list, b: list) -> list`               |
+//! | `list.zip3`      | `(a: list, b: list, c: list) -> list<record>` |
+//! | `list.zip_longest` | `(a: list, b: list, fill) -> list<record>` |
+//! | `list.zip_named` | `(a: list, b: list, name_a: string, name_b: string) -> list<record>` |
+//! | `list.unzip`     | `(pairs: list<record>) -> record`          |
+//! | `list.pairwise`  | `(items: list) -> list<record>`            |
 //! | `list.take`      | `(items: list, n: number) -> list`         |
 //! | `list.drop`      | `(items: list, n: number) -> list`         |
+//! | `list.sample`    | `(items: list, n: number, seed: number) -> list` |
+//! | `list.frequencies` | `(items: list) -> list<record>`          |
+//! | `list.min`       | `(items: list) -> any\|nil`                |
+//! | `list.max`       | `(items: list) -> any\|nil`                |
+//! | `list.all_equal` | `(items: list) -> bool`                    |
+//! | `list.has_duplicates` | `(items: list) -> bool`               |
+//! | `list.merge_records` | `(items: list<record>) -> record`      |
+//! | `list.accumulate` | `(items: list<number>) -> list<number>`   |
+//! | `list.to_record` | `(keys: list<string>, values: list) -> Result<record, string>` |
+//! | `list.collect_results` | `(items: list<Result>) -> Result<list, any>` |
+//! | `list.partition_results` | `(items: list<Result>) -> record`       |
 
 use crate::error::StdlibError;
-use crate::module::StdlibModule;
-use crate::value::Value;
+use crate::module::{Arity, StdlibModule};
+use crate::value::{ResultValue, Value};
+
+/// Safety limit on the length of generated lists (`list.range`,
+/// `list.repeat_with`).
+const MAX_GENERATED_LEN: usize = 10_000_000;
 
 /// The `list` stdlib module.
 pub struct ListModule;
@@ -82,17 +121,23 @@ impl StdlibModule for ListModule {
         matches!(
             function,
             // Construction
-            "empty" | "of" | "repeat" | "range"
+            "empty" | "of" | "repeat" | "range" | "repeat_with" | "range_map_filter"
             // Access
             | "length" | "get" | "first" | "last" | "index_of"
             // Modification
             | "append" | "prepend" | "insert" | "remove" | "update" | "set"
             | "slice" | "concat" | "reverse" | "flatten" | "unique"
+            | "dedup_consecutive" | "shuffle" | "splice" | "concat_all" | "interleave"
+            | "without" | "replace" | "swap"
             // Higher-order
-            | "map" | "filter" | "reduce" | "find" | "find_index"
-            | "every" | "any" | "some" | "sort" | "count"
+            | "map" | "filter" | "reduce" | "find" | "find_index" | "index_of_by"
+            | "every" | "any" | "some" | "sort" | "count" | "is_sorted" | "reduce_while"
+            | "group_consecutive" | "sum_by" | "average_by" | "positions" | "update_where"
             // Query
-            | "contains" | "zip" | "take" | "drop"
+            | "contains" | "zip" | "unzip" | "pairwise" | "take" | "drop" | "sample"
+            | "frequencies" | "zip3" | "min" | "max" | "all_equal" | "has_duplicates"
+            | "merge_records" | "zip_longest" | "accumulate" | "to_record" | "zip_named"
+            | "collect_results" | "partition_results"
         )
     }
 
@@ -103,6 +148,8 @@ impl StdlibModule for ListModule {
             "of" => self.of(args),
             "repeat" => self.repeat(args),
             "range" => self.range(args),
+            "repeat_with" => self.repeat_with(args),
+            "range_map_filter" => self.range_map_filter(args),
             // Access
             "length" => self.length(args),
             "get" => self.get(args),
@@ -120,43 +167,98 @@ impl StdlibModule for ListModule {
             "reverse" => self.reverse(args),
             "flatten" => self.flatten(args),
             "unique" => self.unique(args),
+            "dedup_consecutive" => self.dedup_consecutive(args),
+            "shuffle" => self.shuffle(args),
+            "splice" => self.splice(args),
+            "concat_all" => self.concat_all(args),
+            "interleave" => self.interleave(args),
+            "without" => self.without(args),
+            "replace" => self.replace(args),
+            "swap" => self.swap(args),
             // Higher-order
             "map" => self.map(args),
             "filter" => self.filter(args),
             "reduce" => self.reduce(args),
             "find" => self.find(args),
             "find_index" => self.find_index(args),
+            "index_of_by" => self.index_of_by(args),
             "every" => self.every(args),
             "any" | "some" => self.any(args),
             "sort" => self.sort(args),
             "count" => self.count(args),
+            "is_sorted" => self.is_sorted(args),
+            "reduce_while" => self.reduce_while(args),
+            "group_consecutive" => self.group_consecutive(args),
+            "sum_by" => self.sum_by(args),
+            "average_by" => self.average_by(args),
+            "positions" => self.positions(args),
+            "update_where" => self.update_where(args),
             // Query
             "contains" => self.contains(args),
             "zip" => self.zip(args),
+            "unzip" => self.unzip(args),
+            "pairwise" => self.pairwise(args),
             "take" => self.take(args),
             "drop" => self.drop_fn(args),
+            "sample" => self.sample(args),
+            "frequencies" => self.frequencies(args),
+            "zip3" => self.zip3(args),
+            "min" => self.min(args),
+            "max" => self.max(args),
+            "all_equal" => self.all_equal(args),
+            "has_duplicates" => self.has_duplicates(args),
+            "merge_records" => self.merge_records(args),
+            "zip_longest" => self.zip_longest(args),
+            "accumulate" => self.accumulate(args),
+            "to_record" => self.to_record(args),
+            "zip_named" => self.zip_named(args),
+            "collect_results" => self.collect_results(args),
+            "partition_results" => self.partition_results(args),
             _ => Err(StdlibError::unknown_function("list", function)),
         }
     }
+
+    fn arity(&self, function: &str) -> Option<Arity> {
+        Some(match function {
+            "empty" => Arity::Exact(0),
+            "of" => Arity::AtLeast(0),
+            "repeat" | "range" | "repeat_with" | "get" | "index_of" | "append" | "prepend"
+            | "remove" | "concat" | "shuffle" | "interleave" | "without" | "map" | "filter"
+            | "find" | "find_index" | "every" | "any" | "some" | "count" | "group_consecutive"
+            | "sum_by" | "average_by" | "positions" | "contains" | "zip" | "take" | "drop"
+            | "to_record" => Arity::Exact(2),
+            "range_map_filter" | "splice" | "zip_named" => Arity::Exact(4),
+            "length" | "first" | "last" | "reverse" | "flatten" | "unique"
+            | "dedup_consecutive" | "concat_all" | "unzip" | "pairwise" | "frequencies" | "min"
+            | "max" | "all_equal" | "has_duplicates" | "merge_records" | "accumulate"
+            | "collect_results" | "partition_results" => Arity::Exact(1),
+            "insert" | "update" | "set" | "slice" | "replace" | "swap" | "reduce"
+            | "index_of_by" | "reduce_while" | "update_where" | "sample" | "zip3"
+            | "zip_longest" => Arity::Exact(3),
+            "sort" | "is_sorted" => Arity::Range(1, 2),
+            _ => return None,
+        })
+    }
 }
 
 // ── Helpers ───────────────────────────────────────────────────────────────────
 
-/// Extract a single list argument.
-fn expect_list(fn_name: &str, args: &[Value]) -> Result<Vec<Value>, StdlibError> {
+/// Borrow a single list argument. Ops that build a modified list copy it
+/// with `to_vec`; read-only ops never copy.
+fn expect_list<'a>(fn_name: &str, args: &'a [Value]) -> Result<&'a [Value], StdlibError> {
     if args.len() != 1 {
         return Err(StdlibError::wrong_args(fn_name, 1, args.len()));
     }
     match &args[0] {
-        Value::List(items) => Ok(items.clone()),
+        Value::List(items) => Ok(items),
         other => Err(StdlibError::type_mismatch(fn_name, 1, "list", other.type_name())),
     }
 }
 
-/// Extract a list from the first argument (multi-arg functions).
-fn extract_list(fn_name: &str, val: &Value) -> Result<Vec<Value>, StdlibError> {
+/// Borrow a list from the first argument (multi-arg functions).
+fn extract_list<'a>(fn_name: &str, val: &'a Value) -> Result<&'a [Value], StdlibError> {
     match val {
-        Value::List(items) => Ok(items.clone()),
+        Value::List(items) => Ok(items),
         other => Err(StdlibError::type_mismatch(fn_name, 1, "list", other.type_name())),
     }
 }
@@ -184,6 +286,23 @@ fn extract_number(fn_name: &str, val: &Value, position: usize) -> Result<f64, St
     }
 }
 
+/// Extract a string argument at a given position.
+fn extract_string<'a>(
+    fn_name: &str,
+    val: &'a Value,
+    position: usize,
+) -> Result<&'a str, StdlibError> {
+    match val {
+        Value::String(s) => Ok(s),
+        other => Err(StdlibError::type_mismatch(
+            fn_name,
+            position,
+            "string",
+            other.type_name(),
+        )),
+    }
+}
+
 /// Extract a function argument at a given position.
 fn extract_function(
     fn_name: &str,
@@ -201,6 +320,161 @@ fn extract_function(
     }
 }
 
+/// Reject a callback whose declared arity differs from what `fn_name` will
+/// pass it. Callbacks without a declared arity are always accepted.
+fn check_arity(
+    fn_name: &str,
+    f: &crate::value::StdlibFn,
+    expected: usize,
+) -> Result<(), StdlibError> {
+    match f.arity() {
+        Some(arity) if arity != expected => Err(StdlibError::RuntimeError(format!(
+            "{fn_name}: callback takes {arity} argument{}, but {fn_name} calls it with {expected}",
+            if arity == 1 { "" } else { "s" }
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Smallest (`Less`) or largest (`Greater`) element by natural order.
+///
+/// Only homogeneous lists of numbers, strings, or bools are orderable;
+/// anything else traps. Returns nil for an empty list. Ties keep the first
+/// occurrence.
+fn extreme(fn_name: &str, items: &[Value], want: std::cmp::Ordering) -> Result<Value, StdlibError> {
+    let mut iter = items.iter();
+    let Some(mut best) = iter.next() else {
+        return Ok(Value::Nil);
+    };
+    if !matches!(best, Value::Number(_) | Value::String(_) | Value::Bool(_)) {
+        return Err(StdlibError::RuntimeError(format!(
+            "{fn_name}: cannot order values of type {}",
+            best.type_name()
+        )));
+    }
+    for item in iter {
+        if std::mem::discriminant(item) != std::mem::discriminant(best) {
+            return Err(StdlibError::RuntimeError(format!(
+                "{fn_name}: cannot compare {} with {}",
+                best.type_name(),
+                item.type_name()
+            )));
+        }
+        if item.total_cmp(best) == want {
+            best = item;
+        }
+    }
+    Ok(best.clone())
+}
+
+/// Build a `{ first, second }` record (the shape produced by `list.zip`).
+fn pair_record(first: Value, second: Value) -> Value {
+    let mut fields = std::collections::BTreeMap::new();
+    fields.insert("first".to_string(), first);
+    fields.insert("second".to_string(), second);
+    Value::record(fields)
+}
+
+/// Shared body of `sum_by` / `average_by`: returns the sum of `key_fn(item)`
+/// and the item count. Keys must be numbers and the sum must stay finite.
+fn sum_keys(fn_name: &str, args: Vec<Value>) -> Result<(f64, usize), StdlibError> {
+    if args.len() != 2 {
+        return Err(StdlibError::wrong_args(fn_name, 2, args.len()));
+    }
+    let items = extract_list(fn_name, &args[0])?;
+    let key_fn = extract_function(fn_name, &args[1], 2)?;
+    check_arity(fn_name, &key_fn, 1)?;
+    let mut total = 0.0;
+    for item in items {
+        match key_fn.call(vec![item.clone()])? {
+            Value::Number(n) => total += n,
+            other => {
+                return Err(StdlibError::RuntimeError(format!(
+                    "{fn_name}: key_fn must return a number, got {}",
+                    other.type_name()
+                )))
+            }
+        }
+    }
+    if !total.is_finite() {
+        return Err(StdlibError::RuntimeError(format!(
+            "{fn_name}: sum is not finite"
+        )));
+    }
+    Ok((total, items.len()))
+}
+
+/// Validate `[start, end)` range arguments: both whole numbers, at most
+/// `MAX_GENERATED_LEN` apart. A reversed range yields `start == end` (empty).
+fn range_bounds(fn_name: &str, start: &Value, end: &Value) -> Result<(i64, i64), StdlibError> {
+    let start = extract_number(fn_name, start, 1)?;
+    let end = extract_number(fn_name, end, 2)?;
+    if start.fract() != 0.0 || end.fract() != 0.0 || !start.is_finite() || !end.is_finite() {
+        return Err(StdlibError::RuntimeError(format!(
+            "{fn_name}: start and end must be integers"
+        )));
+    }
+    let start = start as i64;
+    let end = end as i64;
+    if end < start {
+        return Ok((start, start));
+    }
+    // Safety limit: prevent absurdly large ranges. Bounds past ±2^63
+    // saturate, so the span itself can overflow i64.
+    let within_limit = end
+        .checked_sub(start)
+        .is_some_and(|span| span as usize <= MAX_GENERATED_LEN);
+    if !within_limit {
+        return Err(StdlibError::RuntimeError(format!(
+            "{fn_name}: range too large (max 10,000,000 elements)"
+        )));
+    }
+    Ok((start, end))
+}
+
+/// Extract a PRNG seed argument. Any finite number is accepted.
+fn extract_seed(fn_name: &str, val: &Value, position: usize) -> Result<f64, StdlibError> {
+    let seed = extract_number(fn_name, val, position)?;
+    if !seed.is_finite() {
+        return Err(StdlibError::RuntimeError(format!(
+            "{fn_name}: seed must be a finite number"
+        )));
+    }
+    Ok(seed)
+}
+
+/// Deterministic xorshift64 PRNG for seeded list operations.
+///
+/// The same seed always yields the same sequence, so seeded shuffles and
+/// samples stay reproducible across runs and platforms.
+struct Xorshift64(u64);
+
+impl Xorshift64 {
+    fn new(seed: f64) -> Self {
+        // SplitMix64 scramble so nearby seeds (0, 1, 2…) diverge immediately
+        // and the state is never zero (xorshift's only fixed point).
+        let mut z = seed.to_bits().wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        Self(if z == 0 { 0x9E37_79B9_7F4A_7C15 } else { z })
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x
+    }
+
+    /// Uniform-enough index in `0..bound` (bound > 0).
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
+
 // ── Construction ──────────────────────────────────────────────────────────────
 
 impl ListModule {
@@ -238,27 +512,63 @@ impl ListModule {
         if args.len() != 2 {
             return Err(StdlibError::wrong_args("list.range", 2, args.len()));
         }
-        let start = extract_number("list.range", &args[0], 1)?;
-        let end = extract_number("list.range", &args[1], 2)?;
-        if start.fract() != 0.0 || end.fract() != 0.0 || !start.is_finite() || !end.is_finite() {
-            return Err(StdlibError::RuntimeError(
-                "list.range: start and end must be integers".to_string(),
+        let (start, end) = range_bounds("list.range", &args[0], &args[1])?;
+        let items: Vec<Value> = (start..end).map(|i| Value::Number(i as f64)).collect();
+        Ok(Value::List(items))
+    }
+
+    /// `list.range_map_filter(start, end, f, pred) -> list` — fused
+    /// `range` → `map` → `filter`: for each `i` in `[start, end)` computes
+    /// `f(i)` and keeps it when `pred(f(i))` is truthy. Only kept values are
+    /// stored, so large ranges don't materialize. Same bounds and
+    /// 10,000,000-element ceiling as `list.range`.
+    fn range_map_filter(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 4 {
+            return Err(StdlibError::wrong_args(
+                "list.range_map_filter",
+                4,
+                args.len(),
             ));
         }
-        let start = start as i64;
-        let end = end as i64;
-        if end < start {
-            return Ok(Value::List(vec![]));
+        let (start, end) = range_bounds("list.range_map_filter", &args[0], &args[1])?;
+        let f = extract_function("list.range_map_filter", &args[2], 3)?;
+        let pred = extract_function("list.range_map_filter", &args[3], 4)?;
+        check_arity("list.range_map_filter", &f, 1)?;
+        check_arity("list.range_map_filter", &pred, 1)?;
+        let mut result = Vec::new();
+        for i in start..end {
+            let mapped = f.call(vec![Value::Number(i as f64)])?;
+            if pred.call(vec![mapped.clone()])?.is_truthy() {
+                result.push(mapped);
+            }
+        }
+        Ok(Value::List(result))
+    }
+
+    /// `list.repeat_with(count, f) -> list` — `[f(0), f(1), …, f(count - 1)]`.
+    fn repeat_with(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 2 {
+            return Err(StdlibError::wrong_args("list.repeat_with", 2, args.len()));
+        }
+        let count = extract_number("list.repeat_with", &args[0], 1)?;
+        if count.fract() != 0.0 || !count.is_finite() || count < 0.0 {
+            return Err(StdlibError::RuntimeError(
+                "list.repeat_with: count must be a non-negative integer".to_string(),
+            ));
         }
-        // Safety limit: prevent absurdly large ranges
-        let len = (end - start) as usize;
-        if len > 10_000_000 {
+        if count > MAX_GENERATED_LEN as f64 {
             return Err(StdlibError::RuntimeError(
-                "list.range: range too large (max 10,000,000 elements)".to_string(),
+                "list.repeat_with: count too large (max 10,000,000 elements)".to_string(),
             ));
         }
-        let items: Vec<Value> = (start..end).map(|i| Value::Number(i as f64)).collect();
-        Ok(Value::List(items))
+        let f = extract_function("list.repeat_with", &args[1], 2)?;
+        check_arity("list.repeat_with", &f, 1)?;
+        let count = count as usize;
+        let mut result = Vec::with_capacity(count);
+        for i in 0..count {
+            result.push(f.call(vec![Value::Number(i as f64)])?);
+        }
+        Ok(Value::List(result))
     }
 
     // ── Access ────────────────────────────────────────────────────────────────
@@ -317,7 +627,7 @@ impl ListModule {
         if args.len() != 2 {
             return Err(StdlibError::wrong_args("list.append", 2, args.len()));
         }
-        let mut items = extract_list("list.append", &args[0])?;
+        let mut items = extract_list("list.append", &args[0])?.to_vec();
         items.push(args[1].clone());
         Ok(Value::List(items))
     }
@@ -327,7 +637,7 @@ impl ListModule {
         if args.len() != 2 {
             return Err(StdlibError::wrong_args("list.prepend", 2, args.len()));
         }
-        let mut items = extract_list("list.prepend", &args[0])?;
+        let mut items = extract_list("list.prepend", &args[0])?.to_vec();
         items.insert(0, args[1].clone());
         Ok(Value::List(items))
     }
@@ -337,7 +647,7 @@ impl ListModule {
         if args.len() != 3 {
             return Err(StdlibError::wrong_args("list.insert", 3, args.len()));
         }
-        let mut items = extract_list("list.insert", &args[0])?;
+        let mut items = extract_list("list.insert", &args[0])?.to_vec();
         let index = extract_index("list.insert", &args[1], 2)?;
         if index < 0 || index as usize > items.len() {
             return Err(StdlibError::RuntimeError(format!(
@@ -355,7 +665,7 @@ impl ListModule {
         if args.len() != 2 {
             return Err(StdlibError::wrong_args("list.remove", 2, args.len()));
         }
-        let mut items = extract_list("list.remove", &args[0])?;
+        let mut items = extract_list("list.remove", &args[0])?.to_vec();
         let index = extract_index("list.remove", &args[1], 2)?;
         if index < 0 || index as usize >= items.len() {
             return Err(StdlibError::RuntimeError(format!(
@@ -373,7 +683,7 @@ impl ListModule {
         if args.len() != 3 {
             return Err(StdlibError::wrong_args("list.update", 3, args.len()));
         }
-        let mut items = extract_list("list.update", &args[0])?;
+        let mut items = extract_list("list.update", &args[0])?.to_vec();
         let index = extract_index("list.update", &args[1], 2)?;
         if index < 0 || index as usize >= items.len() {
             return Err(StdlibError::RuntimeError(format!(
@@ -386,6 +696,28 @@ impl ListModule {
         Ok(Value::List(items))
     }
 
+    /// `list.swap(items, i, j) -> list` — exchanges the elements at `i` and
+    /// `j`. Both indices must be in bounds; `i == j` returns an equal list.
+    fn swap(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 3 {
+            return Err(StdlibError::wrong_args("list.swap", 3, args.len()));
+        }
+        let mut items = extract_list("list.swap", &args[0])?.to_vec();
+        let i = extract_index("list.swap", &args[1], 2)?;
+        let j = extract_index("list.swap", &args[2], 3)?;
+        for index in [i, j] {
+            if index < 0 || index as usize >= items.len() {
+                return Err(StdlibError::RuntimeError(format!(
+                    "list.swap: index {} out of bounds for list of length {}",
+                    index,
+                    items.len()
+                )));
+            }
+        }
+        items.swap(i as usize, j as usize);
+        Ok(Value::List(items))
+    }
+
     /// `list.slice(items, start, end) -> list` — start inclusive, end exclusive.
     fn slice(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
         if args.len() != 3 {
@@ -409,9 +741,9 @@ impl ListModule {
         if args.len() != 2 {
             return Err(StdlibError::wrong_args("list.concat", 2, args.len()));
         }
-        let mut a = extract_list("list.concat", &args[0])?;
+        let mut a = extract_list("list.concat", &args[0])?.to_vec();
         let b = match &args[1] {
-            Value::List(items) => items.clone(),
+            Value::List(items) => items,
             other => {
                 return Err(StdlibError::type_mismatch(
                     "list.concat",
@@ -421,13 +753,60 @@ impl ListModule {
                 ))
             }
         };
-        a.extend(b);
+        a.extend_from_slice(b);
         Ok(Value::List(a))
     }
 
+    /// `list.concat_all(lists) -> list` — concatenates a list of lists in order.
+    fn concat_all(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let lists = expect_list("list.concat_all", &args)?;
+        let mut result = Vec::new();
+        for (i, item) in lists.iter().enumerate() {
+            match item {
+                Value::List(items) => result.extend_from_slice(items),
+                other => {
+                    return Err(StdlibError::RuntimeError(format!(
+                        "list.concat_all: element {i} must be a list, got {}",
+                        other.type_name()
+                    )));
+                }
+            }
+        }
+        Ok(Value::List(result))
+    }
+
+    /// `list.interleave(a, b) -> list` — alternates elements (`a[0]`, `b[0]`,
+    /// `a[1]`, …), then appends the tail of the longer list.
+    fn interleave(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 2 {
+            return Err(StdlibError::wrong_args("list.interleave", 2, args.len()));
+        }
+        let a = extract_list("list.interleave", &args[0])?;
+        let b = match &args[1] {
+            Value::List(items) => items,
+            other => {
+                return Err(StdlibError::type_mismatch(
+                    "list.interleave",
+                    2,
+                    "list",
+                    other.type_name(),
+                ))
+            }
+        };
+        let mut result = Vec::with_capacity(a.len() + b.len());
+        let (mut a_iter, mut b_iter) = (a.iter(), b.iter());
+        loop {
+            match (a_iter.next(), b_iter.next()) {
+                (None, None) => break,
+                (x, y) => result.extend(x.into_iter().chain(y).cloned()),
+            }
+        }
+        Ok(Value::List(result))
+    }
+
     /// `list.reverse(items) -> list`
     fn reverse(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
-        let mut items = expect_list("list.reverse", &args)?;
+        let mut items = expect_list("list.reverse", &args)?.to_vec();
         items.reverse();
         Ok(Value::List(items))
     }
@@ -438,8 +817,8 @@ impl ListModule {
         let mut result = Vec::new();
         for item in items {
             match item {
-                Value::List(inner) => result.extend(inner),
-                other => result.push(other),
+                Value::List(inner) => result.extend(inner.iter().cloned()),
+                other => result.push(other.clone()),
             }
         }
         Ok(Value::List(result))
@@ -451,14 +830,97 @@ impl ListModule {
         let mut seen = Vec::new();
         let mut result = Vec::new();
         for item in items {
-            if !seen.contains(&item) {
+            if !seen.contains(item) {
                 seen.push(item.clone());
-                result.push(item);
+                result.push(item.clone());
             }
         }
         Ok(Value::List(result))
     }
 
+    /// `list.dedup_consecutive(items) -> list` — collapses runs of equal adjacent
+    /// elements into one (like Unix `uniq`). Non-adjacent repeats are kept.
+    fn dedup_consecutive(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let mut items = expect_list("list.dedup_consecutive", &args)?.to_vec();
+        items.dedup();
+        Ok(Value::List(items))
+    }
+
+    /// `list.without(items, value) -> list` — removes every element equal to
+    /// `value`.
+    fn without(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 2 {
+            return Err(StdlibError::wrong_args("list.without", 2, args.len()));
+        }
+        let mut items = extract_list("list.without", &args[0])?.to_vec();
+        items.retain(|item| item != &args[1]);
+        Ok(Value::List(items))
+    }
+
+    /// `list.replace(items, old, new) -> list` — replaces every element equal
+    /// to `old` with `new`.
+    fn replace(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 3 {
+            return Err(StdlibError::wrong_args("list.replace", 3, args.len()));
+        }
+        let mut items = extract_list("list.replace", &args[0])?.to_vec();
+        for item in items.iter_mut().filter(|item| **item == args[1]) {
+            *item = args[2].clone();
+        }
+        Ok(Value::List(items))
+    }
+
+    /// `list.shuffle(items, seed) -> list` — deterministic Fisher–Yates shuffle.
+    ///
+    /// The permutation depends only on `seed`, so the same seed always yields
+    /// the same order.
+    fn shuffle(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 2 {
+            return Err(StdlibError::wrong_args("list.shuffle", 2, args.len()));
+        }
+        let mut items = extract_list("list.shuffle", &args[0])?.to_vec();
+        let seed = extract_seed("list.shuffle", &args[1], 2)?;
+        let mut rng = Xorshift64::new(seed);
+        for i in (1..items.len()).rev() {
+            let j = rng.below(i + 1);
+            items.swap(i, j);
+        }
+        Ok(Value::List(items))
+    }
+
+    /// `list.splice(items, start, delete_count, insert) -> list` — removes
+    /// `delete_count` elements at `start` and inserts `insert` in their place.
+    /// `start` and the removed range are clamped to the list bounds.
+    fn splice(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 4 {
+            return Err(StdlibError::wrong_args("list.splice", 4, args.len()));
+        }
+        let mut items = extract_list("list.splice", &args[0])?.to_vec();
+        let start = extract_index("list.splice", &args[1], 2)?;
+        let delete_count = extract_index("list.splice", &args[2], 3)?;
+        if delete_count < 0 {
+            return Err(StdlibError::RuntimeError(format!(
+                "list.splice: delete_count must be non-negative, got {delete_count}"
+            )));
+        }
+        let insert = match &args[3] {
+            Value::List(insert) => insert.iter().cloned(),
+            other => {
+                return Err(StdlibError::type_mismatch(
+                    "list.splice",
+                    4,
+                    "list",
+                    other.type_name(),
+                ))
+            }
+        };
+        let len = items.len() as i64;
+        let start = start.clamp(0, len);
+        let end = start.saturating_add(delete_count).min(len);
+        items.splice(start as usize..end as usize, insert);
+        Ok(Value::List(items))
+    }
+
     // ── Higher-Order ──────────────────────────────────────────────────────────
 
     /// `list.map(items, f) -> list` — applies f to each element.
@@ -468,9 +930,10 @@ impl ListModule {
         }
         let items = extract_list("list.map", &args[0])?;
         let f = extract_function("list.map", &args[1], 2)?;
+        check_arity("list.map", &f, 1)?;
         let mut result = Vec::with_capacity(items.len());
         for item in items {
-            result.push(f.call(vec![item])?);
+            result.push(f.call(vec![item.clone()])?);
         }
         Ok(Value::List(result))
     }
@@ -486,7 +949,7 @@ impl ListModule {
         for item in items {
             let keep = pred.call(vec![item.clone()])?;
             if keep.is_truthy() {
-                result.push(item);
+                result.push(item.clone());
             }
         }
         Ok(Value::List(result))
@@ -500,8 +963,46 @@ impl ListModule {
         let items = extract_list("list.reduce", &args[0])?;
         let mut acc = args[1].clone();
         let f = extract_function("list.reduce", &args[2], 3)?;
+        check_arity("list.reduce", &f, 2)?;
         for item in items {
-            acc = f.call(vec![acc, item])?;
+            acc = f.call(vec![acc, item.clone()])?;
+        }
+        Ok(acc)
+    }
+
+    /// `list.reduce_while(items, initial, step) -> any` — short-circuiting fold.
+    ///
+    /// `step(acc, item)` must return a `{acc, continue}` record. The new `acc`
+    /// is always kept; iteration stops after the first step whose `continue`
+    /// is falsy. Returns the last accumulator (`initial` for an empty list).
+    fn reduce_while(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 3 {
+            return Err(StdlibError::wrong_args("list.reduce_while", 3, args.len()));
+        }
+        let items = extract_list("list.reduce_while", &args[0])?;
+        let mut acc = args[1].clone();
+        let step = extract_function("list.reduce_while", &args[2], 3)?;
+        check_arity("list.reduce_while", &step, 2)?;
+        for item in items {
+            let result = step.call(vec![acc, item.clone()])?;
+            let fields = match &result {
+                Value::Record { fields, .. } => fields,
+                other => {
+                    return Err(StdlibError::RuntimeError(format!(
+                        "list.reduce_while: step must return an {{acc, continue}} record, got {}",
+                        other.type_name()
+                    )))
+                }
+            };
+            let (Some(next), Some(keep_going)) = (fields.get("acc"), fields.get("continue")) else {
+                return Err(StdlibError::RuntimeError(
+                    "list.reduce_while: step result is missing 'acc' or 'continue'".to_string(),
+                ));
+            };
+            acc = next.clone();
+            if !keep_going.is_truthy() {
+                break;
+            }
         }
         Ok(acc)
     }
@@ -516,7 +1017,7 @@ impl ListModule {
         for item in items {
             let matches = pred.call(vec![item.clone()])?;
             if matches.is_truthy() {
-                return Ok(item);
+                return Ok(item.clone());
             }
         }
         Ok(Value::Nil)
@@ -529,8 +1030,8 @@ impl ListModule {
         }
         let items = extract_list("list.find_index", &args[0])?;
         let pred = extract_function("list.find_index", &args[1], 2)?;
-        for (i, item) in items.into_iter().enumerate() {
-            let matches = pred.call(vec![item])?;
+        for (i, item) in items.iter().enumerate() {
+            let matches = pred.call(vec![item.clone()])?;
             if matches.is_truthy() {
                 return Ok(Value::Number(i as f64));
             }
@@ -538,6 +1039,23 @@ impl ListModule {
         Ok(Value::Number(-1.0))
     }
 
+    /// `list.index_of_by(items, target, key_fn) -> number` — index of the first
+    /// element whose `key_fn` result equals `target`, or -1.
+    fn index_of_by(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 3 {
+            return Err(StdlibError::wrong_args("list.index_of_by", 3, args.len()));
+        }
+        let items = extract_list("list.index_of_by", &args[0])?;
+        let target = &args[1];
+        let key_fn = extract_function("list.index_of_by", &args[2], 3)?;
+        for (i, item) in items.iter().enumerate() {
+            if key_fn.call(vec![item.clone()])? == *target {
+                return Ok(Value::Number(i as f64));
+            }
+        }
+        Ok(Value::Number(-1.0))
+    }
+
     /// `list.every(items, predicate) -> bool` — true if pred holds for all.
     fn every(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
         if args.len() != 2 {
@@ -546,7 +1064,7 @@ impl ListModule {
         let items = extract_list("list.every", &args[0])?;
         let pred = extract_function("list.every", &args[1], 2)?;
         for item in items {
-            let result = pred.call(vec![item])?;
+            let result = pred.call(vec![item.clone()])?;
             if !result.is_truthy() {
                 return Ok(Value::Bool(false));
             }
@@ -563,7 +1081,7 @@ impl ListModule {
         let items = extract_list("list.any", &args[0])?;
         let pred = extract_function("list.any", &args[1], 2)?;
         for item in items {
-            let result = pred.call(vec![item])?;
+            let result = pred.call(vec![item.clone()])?;
             if result.is_truthy() {
                 return Ok(Value::Bool(true));
             }
@@ -571,18 +1089,26 @@ impl ListModule {
         Ok(Value::Bool(false))
     }
 
-    /// `list.sort(items, compare) -> list` — stable sort using comparator.
+    /// `list.sort(items, compare?) -> list` — stable sort using comparator.
     ///
     /// The comparator `fn(a, b) -> number` must return:
     /// - negative if a < b
     /// - zero if a == b
     /// - positive if a > b
+    ///
+    /// Without a comparator, sorts by [`Value::total_cmp`] (natural order).
     fn sort(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
-        if args.len() != 2 {
-            return Err(StdlibError::wrong_args("list.sort", 2, args.len()));
+        if args.is_empty() || args.len() > 2 {
+            let expected = if args.is_empty() { 1 } else { 2 };
+            return Err(StdlibError::wrong_args("list.sort", expected, args.len()));
+        }
+        let mut items = extract_list("list.sort", &args[0])?.to_vec();
+        if args.len() == 1 {
+            items.sort_by(|a, b| a.total_cmp(b));
+            return Ok(Value::List(items));
         }
-        let mut items = extract_list("list.sort", &args[0])?;
         let cmp = extract_function("list.sort", &args[1], 2)?;
+        check_arity("list.sort", &cmp, 2)?;
 
         // We need to propagate errors from the comparator, so we use a cell
         // to capture the first error that occurs during sorting.
@@ -622,6 +1148,45 @@ impl ListModule {
         Ok(Value::List(items))
     }
 
+    /// `list.is_sorted(items, compare?) -> bool` — true if every adjacent pair
+    /// is non-decreasing per `compare` (same contract as `list.sort`).
+    ///
+    /// Without a comparator, uses [`Value::total_cmp`]. Empty and
+    /// single-element lists are trivially sorted. Stops at the first
+    /// out-of-order pair.
+    fn is_sorted(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.is_empty() || args.len() > 2 {
+            let expected = if args.is_empty() { 1 } else { 2 };
+            return Err(StdlibError::wrong_args(
+                "list.is_sorted",
+                expected,
+                args.len(),
+            ));
+        }
+        let items = extract_list("list.is_sorted", &args[0])?;
+        if args.len() == 1 {
+            let sorted = items
+                .windows(2)
+                .all(|w| w[0].total_cmp(&w[1]) != std::cmp::Ordering::Greater);
+            return Ok(Value::Bool(sorted));
+        }
+        let cmp = extract_function("list.is_sorted", &args[1], 2)?;
+        check_arity("list.is_sorted", &cmp, 2)?;
+        for pair in items.windows(2) {
+            match cmp.call(vec![pair[0].clone(), pair[1].clone()])? {
+                Value::Number(n) if n > 0.0 => return Ok(Value::Bool(false)),
+                Value::Number(_) => {}
+                other => {
+                    return Err(StdlibError::RuntimeError(format!(
+                        "list.is_sorted: comparator must return a number, got {}",
+                        other.type_name()
+                    )))
+                }
+            }
+        }
+        Ok(Value::Bool(true))
+    }
+
     /// `list.count(items, predicate) -> number` — counts elements matching pred.
     fn count(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
         if args.len() != 2 {
@@ -631,7 +1196,7 @@ impl ListModule {
         let pred = extract_function("list.count", &args[1], 2)?;
         let mut n = 0usize;
         for item in items {
-            let result = pred.call(vec![item])?;
+            let result = pred.call(vec![item.clone()])?;
             if result.is_truthy() {
                 n += 1;
             }
@@ -639,6 +1204,96 @@ impl ListModule {
         Ok(Value::Number(n as f64))
     }
 
+    /// `list.group_consecutive(items, key_fn) -> list<record>` — run-length
+    /// grouping: one `{key, items}` record per maximal run of adjacent elements
+    /// whose `key_fn` results are equal. Unlike a full group-by, a key that
+    /// reappears later starts a new run.
+    fn group_consecutive(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 2 {
+            return Err(StdlibError::wrong_args(
+                "list.group_consecutive",
+                2,
+                args.len(),
+            ));
+        }
+        let items = extract_list("list.group_consecutive", &args[0])?;
+        let key_fn = extract_function("list.group_consecutive", &args[1], 2)?;
+        check_arity("list.group_consecutive", &key_fn, 1)?;
+        let mut runs: Vec<(Value, Vec<Value>)> = Vec::new();
+        for item in items {
+            let key = key_fn.call(vec![item.clone()])?;
+            match runs.last_mut() {
+                Some((last_key, run)) if *last_key == key => run.push(item.clone()),
+                _ => runs.push((key, vec![item.clone()])),
+            }
+        }
+        let result = runs
+            .into_iter()
+            .map(|(key, run)| Value::record_of(&[("key", key), ("items", Value::List(run))]))
+            .collect();
+        Ok(Value::List(result))
+    }
+
+    /// `list.sum_by(items, key_fn) -> number` — sum of `key_fn(item)` over
+    /// all items; 0 for an empty list. Keys must be numbers.
+    fn sum_by(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let (total, _) = sum_keys("list.sum_by", args)?;
+        Ok(Value::Number(total))
+    }
+
+    /// `list.average_by(items, key_fn) -> number` — mean of `key_fn(item)`.
+    /// Traps on an empty list, which has no defined mean.
+    fn average_by(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let (total, count) = sum_keys("list.average_by", args)?;
+        if count == 0 {
+            return Err(StdlibError::RuntimeError(
+                "list.average_by: cannot average an empty list".to_string(),
+            ));
+        }
+        Ok(Value::Number(total / count as f64))
+    }
+
+    /// `list.positions(items, predicate) -> list<number>` — every index where
+    /// pred is truthy, ascending. Empty when nothing matches.
+    fn positions(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 2 {
+            return Err(StdlibError::wrong_args("list.positions", 2, args.len()));
+        }
+        let items = extract_list("list.positions", &args[0])?;
+        let pred = extract_function("list.positions", &args[1], 2)?;
+        check_arity("list.positions", &pred, 1)?;
+        let mut result = Vec::new();
+        for (i, item) in items.iter().enumerate() {
+            if pred.call(vec![item.clone()])?.is_truthy() {
+                result.push(Value::Number(i as f64));
+            }
+        }
+        Ok(Value::List(result))
+    }
+
+    /// `list.update_where(items, predicate, f) -> list` — applies `f` to each
+    /// element for which `predicate` is truthy; other elements are kept as-is.
+    /// `f` only runs on matching elements.
+    fn update_where(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 3 {
+            return Err(StdlibError::wrong_args("list.update_where", 3, args.len()));
+        }
+        let items = extract_list("list.update_where", &args[0])?;
+        let pred = extract_function("list.update_where", &args[1], 2)?;
+        let f = extract_function("list.update_where", &args[2], 3)?;
+        check_arity("list.update_where", &pred, 1)?;
+        check_arity("list.update_where", &f, 1)?;
+        let mut result = Vec::with_capacity(items.len());
+        for item in items {
+            if pred.call(vec![item.clone()])?.is_truthy() {
+                result.push(f.call(vec![item.clone()])?);
+            } else {
+                result.push(item.clone());
+            }
+        }
+        Ok(Value::List(result))
+    }
+
     // ── Query ─────────────────────────────────────────────────────────────────
 
     /// `list.contains(items, value) -> bool` — value equality check.
@@ -661,7 +1316,7 @@ impl ListModule {
         }
         let a = extract_list("list.zip", &args[0])?;
         let b = match &args[1] {
-            Value::List(items) => items.clone(),
+            Value::List(items) => items,
             other => {
                 return Err(StdlibError::type_mismatch(
                     "list.zip",
@@ -671,19 +1326,157 @@ impl ListModule {
                 ))
             }
         };
+        let result: Vec<Value> = a
+            .iter()
+            .zip(b.iter())
+            .map(|(first, second)| pair_record(first.clone(), second.clone()))
+            .collect();
+        Ok(Value::List(result))
+    }
+
+    /// `list.zip_longest(a, b, fill) -> list` — like `list.zip`, but runs to
+    /// the longer list, using `fill` in place of the exhausted side.
+    fn zip_longest(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 3 {
+            return Err(StdlibError::wrong_args("list.zip_longest", 3, args.len()));
+        }
+        let a = extract_list("list.zip_longest", &args[0])?;
+        let b = match &args[1] {
+            Value::List(items) => items,
+            other => {
+                return Err(StdlibError::type_mismatch(
+                    "list.zip_longest",
+                    2,
+                    "list",
+                    other.type_name(),
+                ))
+            }
+        };
+        let fill = &args[2];
+        let len = a.len().max(b.len());
+        let (mut a, mut b) = (a.iter().cloned(), b.iter().cloned());
+        let result: Vec<Value> = (0..len)
+            .map(|_| {
+                let first = a.next().unwrap_or_else(|| fill.clone());
+                let second = b.next().unwrap_or_else(|| fill.clone());
+                pair_record(first, second)
+            })
+            .collect();
+        Ok(Value::List(result))
+    }
+
+    /// `list.zip_named(a, b, name_a, name_b) -> list` — like `list.zip`, but
+    /// records use the given field names instead of `first`/`second`.
+    /// Stops at the shorter list. The two names must differ.
+    fn zip_named(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 4 {
+            return Err(StdlibError::wrong_args("list.zip_named", 4, args.len()));
+        }
+        let a = extract_list("list.zip_named", &args[0])?;
+        let b = match &args[1] {
+            Value::List(items) => items,
+            other => {
+                return Err(StdlibError::type_mismatch(
+                    "list.zip_named",
+                    2,
+                    "list",
+                    other.type_name(),
+                ))
+            }
+        };
+        let name_a = extract_string("list.zip_named", &args[2], 3)?;
+        let name_b = extract_string("list.zip_named", &args[3], 4)?;
+        if name_a == name_b {
+            return Err(StdlibError::RuntimeError(format!(
+                "list.zip_named: field names must differ, got '{name_a}' twice"
+            )));
+        }
+        let result: Vec<Value> = a
+            .iter()
+            .zip(b.iter())
+            .map(|(x, y)| Value::record_of(&[(name_a, x.clone()), (name_b, y.clone())]))
+            .collect();
+        Ok(Value::List(result))
+    }
+
+    /// `list.zip3(a, b, c) -> list` — `{ first, second, third }` records,
+    /// stopping at the shortest list.
+    fn zip3(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 3 {
+            return Err(StdlibError::wrong_args("list.zip3", 3, args.len()));
+        }
+        let mut lists = Vec::with_capacity(3);
+        for (i, arg) in args.into_iter().enumerate() {
+            match arg {
+                Value::List(items) => lists.push(items),
+                other => {
+                    return Err(StdlibError::type_mismatch(
+                        "list.zip3",
+                        i + 1,
+                        "list",
+                        other.type_name(),
+                    ))
+                }
+            }
+        }
+        let c = lists.pop().unwrap_or_default();
+        let b = lists.pop().unwrap_or_default();
+        let a = lists.pop().unwrap_or_default();
         let result: Vec<Value> = a
             .into_iter()
             .zip(b)
-            .map(|(first, second)| {
+            .zip(c)
+            .map(|((first, second), third)| {
                 let mut fields = std::collections::BTreeMap::new();
                 fields.insert("first".to_string(), first);
                 fields.insert("second".to_string(), second);
+                fields.insert("third".to_string(), third);
                 Value::record(fields)
             })
             .collect();
         Ok(Value::List(result))
     }
 
+    /// `list.unzip(pairs) -> record` — inverse of `list.zip`.
+    ///
+    /// Takes a list of `{ first, second }` records and returns
+    /// `{ firsts, seconds }`, each a list. Every element must have both fields.
+    fn unzip(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let pairs = expect_list("list.unzip", &args)?;
+        let mut firsts = Vec::with_capacity(pairs.len());
+        let mut seconds = Vec::with_capacity(pairs.len());
+        for (i, pair) in pairs.iter().enumerate() {
+            let fields = pair.as_record();
+            match fields.and_then(|f| Some((f.get("first")?, f.get("second")?))) {
+                Some((first, second)) => {
+                    firsts.push(first.clone());
+                    seconds.push(second.clone());
+                }
+                None => {
+                    return Err(StdlibError::RuntimeError(format!(
+                        "list.unzip: element {i} must be a {{ first, second }} record, got {}",
+                        pair.type_name()
+                    )));
+                }
+            }
+        }
+        let mut fields = std::collections::BTreeMap::new();
+        fields.insert("firsts".to_string(), Value::List(firsts));
+        fields.insert("seconds".to_string(), Value::List(seconds));
+        Ok(Value::record(fields))
+    }
+
+    /// `list.pairwise(items) -> list` — `{ first, second }` records for each
+    /// adjacent pair, so n elements yield n-1 pairs (none for n < 2).
+    fn pairwise(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let items = expect_list("list.pairwise", &args)?;
+        let result: Vec<Value> = items
+            .windows(2)
+            .map(|w| pair_record(w[0].clone(), w[1].clone()))
+            .collect();
+        Ok(Value::List(result))
+    }
+
     /// `list.take(items, n) -> list` — takes first n elements.
     fn take(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
         if args.len() != 2 {
@@ -718,4 +1511,228 @@ impl ListModule {
         let n = (n as usize).min(items.len());
         Ok(Value::List(items[n..].to_vec()))
     }
+
+    /// `list.sample(items, n, seed) -> list` — `n` elements chosen without
+    /// replacement using a seeded PRNG.
+    ///
+    /// `n` is clamped to the list length, so `n >= length` returns a
+    /// permutation of the whole list. The same seed reproduces the same sample.
+    fn sample(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 3 {
+            return Err(StdlibError::wrong_args("list.sample", 3, args.len()));
+        }
+        let mut items = extract_list("list.sample", &args[0])?.to_vec();
+        let n = extract_number("list.sample", &args[1], 2)?;
+        if n.fract() != 0.0 || !n.is_finite() || n < 0.0 {
+            return Err(StdlibError::RuntimeError(
+                "list.sample: count must be a non-negative integer".to_string(),
+            ));
+        }
+        let seed = extract_seed("list.sample", &args[2], 3)?;
+        let n = (n as usize).min(items.len());
+
+        // Partial Fisher–Yates: only the first n slots need to be settled.
+        let mut rng = Xorshift64::new(seed);
+        let len = items.len();
+        for i in 0..n {
+            let j = i + rng.below(len - i);
+            items.swap(i, j);
+        }
+        items.truncate(n);
+        Ok(Value::List(items))
+    }
+
+    /// `list.frequencies(items) -> list` — one `{ value, count }` record per
+    /// distinct value (by equality), in first-occurrence order.
+    fn frequencies(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let items = expect_list("list.frequencies", &args)?;
+        let mut counts: Vec<(Value, usize)> = Vec::new();
+        for item in items {
+            match counts.iter_mut().find(|(v, _)| v == item) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((item.clone(), 1)),
+            }
+        }
+        let result = counts
+            .into_iter()
+            .map(|(value, count)| {
+                let mut fields = std::collections::BTreeMap::new();
+                fields.insert("value".to_string(), value);
+                fields.insert("count".to_string(), Value::Number(count as f64));
+                Value::record(fields)
+            })
+            .collect();
+        Ok(Value::List(result))
+    }
+
+    /// `list.min(items) -> any|nil` — smallest number, string, or bool.
+    fn min(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let items = expect_list("list.min", &args)?;
+        extreme("list.min", items, std::cmp::Ordering::Less)
+    }
+
+    /// `list.max(items) -> any|nil` — largest number, string, or bool.
+    fn max(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let items = expect_list("list.max", &args)?;
+        extreme("list.max", items, std::cmp::Ordering::Greater)
+    }
+
+    /// `list.all_equal(items) -> bool` — true if every element equals the
+    /// first. Vacuously true for empty and single-element lists.
+    fn all_equal(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let items = expect_list("list.all_equal", &args)?;
+        Ok(Value::Bool(items.windows(2).all(|w| w[0] == w[1])))
+    }
+
+    /// `list.has_duplicates(items) -> bool` — true if any two elements are
+    /// equal.
+    fn has_duplicates(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let items = expect_list("list.has_duplicates", &args)?;
+        let found = items
+            .iter()
+            .enumerate()
+            .any(|(i, item)| items[i + 1..].contains(item));
+        Ok(Value::Bool(found))
+    }
+
+    /// `list.merge_records(items) -> record` — folds a list of records into
+    /// one anonymous record; later records overwrite earlier keys. An empty
+    /// list yields an empty record.
+    fn merge_records(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let items = expect_list("list.merge_records", &args)?;
+        let mut merged = std::collections::BTreeMap::new();
+        for (i, item) in items.iter().enumerate() {
+            match item {
+                Value::Record { fields, .. } => merged.extend(fields.clone()),
+                other => {
+                    return Err(StdlibError::RuntimeError(format!(
+                        "list.merge_records: element {i} must be a record, got {}",
+                        other.type_name()
+                    )))
+                }
+            }
+        }
+        Ok(Value::record(merged))
+    }
+
+    /// `list.accumulate(items) -> list<number>` — running totals (prefix
+    /// sums): `[1, 2, 3]` → `[1, 3, 6]`. An empty list yields `[]`. Traps if
+    /// an element isn't a number or a partial sum overflows to infinity.
+    fn accumulate(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let items = expect_list("list.accumulate", &args)?;
+        let mut total = 0.0;
+        let mut result = Vec::with_capacity(items.len());
+        for (i, item) in items.iter().enumerate() {
+            let Value::Number(n) = item else {
+                return Err(StdlibError::RuntimeError(format!(
+                    "list.accumulate: element {i} must be a number, got {}",
+                    item.type_name()
+                )));
+            };
+            total += n;
+            if !total.is_finite() {
+                return Err(StdlibError::RuntimeError(format!(
+                    "list.accumulate: running total at element {i} is not finite"
+                )));
+            }
+            result.push(Value::Number(total));
+        }
+        Ok(Value::List(result))
+    }
+
+    /// `list.to_record(keys, values) -> Result<record, string>` — pairs
+    /// `keys[i]` with `values[i]` into an anonymous record. Err if the lists
+    /// differ in length or a key isn't a string. Later duplicate keys
+    /// overwrite earlier ones.
+    fn to_record(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 2 {
+            return Err(StdlibError::wrong_args("list.to_record", 2, args.len()));
+        }
+        let keys = extract_list("list.to_record", &args[0])?;
+        let values = match &args[1] {
+            Value::List(items) => items,
+            other => {
+                return Err(StdlibError::type_mismatch(
+                    "list.to_record",
+                    2,
+                    "list",
+                    other.type_name(),
+                ))
+            }
+        };
+        if keys.len() != values.len() {
+            return Ok(Value::String(format!(
+                "keys and values differ in length ({} vs {})",
+                keys.len(),
+                values.len()
+            ))
+            .err());
+        }
+        let mut fields = std::collections::BTreeMap::new();
+        for (i, (key, value)) in keys.iter().zip(values.iter()).enumerate() {
+            match key {
+                Value::String(key) => {
+                    fields.insert(key.clone(), value.clone());
+                }
+                other => {
+                    return Ok(Value::String(format!(
+                        "key {i} must be a string, got {}",
+                        other.type_name()
+                    ))
+                    .err())
+                }
+            }
+        }
+        Ok(Value::record(fields).ok())
+    }
+
+    /// `list.collect_results(items) -> Result<list, any>` — Ok with every
+    /// unwrapped payload if all elements are Ok, otherwise the first Err
+    /// (later elements are not inspected). Traps if an element before the
+    /// first Err isn't a Result.
+    fn collect_results(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let items = expect_list("list.collect_results", &args)?;
+        let mut oks = Vec::with_capacity(items.len());
+        for (i, item) in items.iter().enumerate() {
+            match item {
+                Value::Result(r) => match r.as_ref() {
+                    ResultValue::Ok(v) => oks.push(v.clone()),
+                    ResultValue::Err(_) => return Ok(item.clone()),
+                },
+                other => {
+                    return Err(StdlibError::RuntimeError(format!(
+                        "list.collect_results: element {i} must be a result, got {}",
+                        other.type_name()
+                    )))
+                }
+            }
+        }
+        Ok(Value::List(oks).ok())
+    }
+
+    /// `list.partition_results(items) -> record` — `{ oks, errs }` holding
+    /// the unwrapped Ok and Err payloads, each in original order. Traps if an
+    /// element isn't a Result.
+    fn partition_results(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let items = expect_list("list.partition_results", &args)?;
+        let (mut oks, mut errs) = (Vec::new(), Vec::new());
+        for (i, item) in items.iter().enumerate() {
+            match item {
+                Value::Result(r) => match r.as_ref() {
+                    ResultValue::Ok(v) => oks.push(v.clone()),
+                    ResultValue::Err(e) => errs.push(e.clone()),
+                },
+                other => {
+                    return Err(StdlibError::RuntimeError(format!(
+                        "list.partition_results: element {i} must be a result, got {}",
+                        other.type_name()
+                    )))
+                }
+            }
+        }
+        Ok(Value::record_of(&[
+            ("oks", Value::List(oks)),
+            ("errs", Value::List(errs)),
+        ]))
+    }
 }
@@ -14,7 +14,7 @@
 
 use crate::capability::{CAP_LOCATION, LOCATION_CURRENT};
 use crate::error::StdlibError;
-use crate::module::StdlibModule;
+use crate::module::{Arity, StdlibModule};
 use crate::value::Value;
 
 /// The `location` capability module.
@@ -47,6 +47,13 @@ impl StdlibModule for LocationModule {
             _ => Err(StdlibError::unknown_function("location", function)),
         }
     }
+
+    fn arity(&self, function: &str) -> Option<Arity> {
+        Some(match function {
+            "current" => Arity::Exact(0),
+            _ => return None,
+        })
+    }
 }
 
 impl LocationModule {
@@ -1,4 +1,4 @@
-//! The `math` module — 10 functions + 2 constants.
+//! The `math` module — 24 functions + 2 constants.
 //!
 //! | Function     | Signature                                  | Description                  |
 //! |--------------|--------------------------------------------|------------------------------|
@@ -8,17 +8,35 @@
 //! | `math.floor` | `(a: number) -> number`                    | Round down                   |
 //! | `math.ceil`  | `(a: number) -> number`                    | Round up                     |
 //! | `math.round` | `(a: number) -> number`                    | Round (0.5 rounds up)        |
+//! | `math.round_half_even` | `(a: number) -> number`           | Round (ties to even)         |
 //! | `math.round_to` | `(a: number, decimals: number) -> number` | Round to N decimal places |
 //! | `math.pow`   | `(base: number, exp: number) -> number`    | Exponentiation               |
 //! | `math.clamp` | `(value: number, min: number, max: number) -> number` | Clamp to range |
 //! | `math.sqrt`  | `(a: number) -> number`                    | Square root (trap on negative) |
+//! | `math.map_range` | `(value, in_min, in_max, out_min, out_max) -> number` | Proportional remap |
+//! | `math.normalize` | `(value: number, min: number, max: number) -> number` | Position in range, clamped to \[0, 1\] |
+//! | `math.factorial` | `(n: number) -> number`                | n! (traps past 170!)         |
+//! | `math.combinations` | `(n: number, k: number) -> number`  | Binomial coefficient C(n, k) |
+//! | `math.is_close` | `(a: number, b: number, rel_tol: number) -> bool` | Tolerant equality |
+//! | `math.clamp01` | `(value: number) -> number`               | Clamp to \[0, 1\] (alias `saturate`) |
+//! | `math.degrees` | `(radians: number) -> number`            | Radians to degrees           |
+//! | `math.radians` | `(degrees: number) -> number`            | Degrees to radians           |
+//! | `math.clamp_magnitude` | `(value: number, max_abs: number) -> number` | Cap \|value\|, keep sign |
+//! | `math.wrap`  | `(value: number, min: number, max: number) -> number` | Wrap cyclically into \[min, max) |
+//! | `math.snap_integer` | `(value: number, tolerance: number) -> number` | Snap near-integers to whole |
+//! | `math.floor_to` | `(value: number, step: number) -> number` | Round down to a multiple of `step` |
+//! | `math.ceil_to` | `(value: number, step: number) -> number`  | Round up to a multiple of `step` |
 //! | `math.PI`    | constant `number`                          | 3.14159265358979…            |
 //! | `math.E`     | constant `number`                          | 2.71828182845904…            |
 
 use crate::error::StdlibError;
-use crate::module::StdlibModule;
+use crate::module::{Arity, StdlibModule};
 use crate::value::Value;
 
+/// Absolute tolerance floor used by `math.is_close`, so comparisons against
+/// (or very near) zero don't require exact equality.
+const IS_CLOSE_ABS_TOL: f64 = 1e-12;
+
 /// The `math` stdlib module.
 pub struct MathModule;
 
@@ -52,6 +70,21 @@ impl StdlibModule for MathModule {
                 | "pow"
                 | "clamp"
                 | "sqrt"
+                | "map_range"
+                | "factorial"
+                | "combinations"
+                | "is_close"
+                | "clamp01"
+                | "saturate"
+                | "round_half_even"
+                | "degrees"
+                | "radians"
+                | "normalize"
+                | "clamp_magnitude"
+                | "wrap"
+                | "snap_integer"
+                | "floor_to"
+                | "ceil_to"
                 | "PI"
                 | "E"
         )
@@ -69,12 +102,39 @@ impl StdlibModule for MathModule {
             "pow" => self.pow(args),
             "clamp" => self.clamp(args),
             "sqrt" => self.sqrt(args),
+            "map_range" => self.map_range(args),
+            "factorial" => self.factorial(args),
+            "combinations" => self.combinations(args),
+            "is_close" => self.is_close(args),
+            "clamp01" | "saturate" => self.clamp01(args),
+            "round_half_even" => self.round_half_even(args),
+            "degrees" => self.degrees(args),
+            "radians" => self.radians(args),
+            "normalize" => self.normalize(args),
+            "clamp_magnitude" => self.clamp_magnitude(args),
+            "wrap" => self.wrap(args),
+            "snap_integer" => self.snap_integer(args),
+            "floor_to" => self.floor_to(args),
+            "ceil_to" => self.ceil_to(args),
             // Constants are dispatched as zero-arg "calls"
             "PI" => self.pi(args),
             "E" => self.e(args),
             _ => Err(StdlibError::unknown_function("math", function)),
         }
     }
+
+    fn arity(&self, function: &str) -> Option<Arity> {
+        Some(match function {
+            "abs" | "floor" | "ceil" | "round" | "sqrt" | "factorial" | "clamp01" | "saturate"
+            | "round_half_even" | "degrees" | "radians" => Arity::Exact(1),
+            "min" | "max" | "round_to" | "pow" | "combinations" | "clamp_magnitude"
+            | "snap_integer" | "floor_to" | "ceil_to" => Arity::Exact(2),
+            "clamp" | "is_close" | "normalize" | "wrap" => Arity::Exact(3),
+            "map_range" => Arity::Exact(5),
+            "PI" | "E" => Arity::Exact(0),
+            _ => return None,
+        })
+    }
 }
 
 // ── Helpers ───────────────────────────────────────────────────────────────────
@@ -125,6 +185,28 @@ fn expect_two_numbers(fn_name: &str, args: &[Value]) -> Result<(f64, f64), Stdli
     Ok((a, b))
 }
 
+/// Extract exactly `N` `Number` arguments.
+fn expect_numbers<const N: usize>(fn_name: &str, args: &[Value]) -> Result<[f64; N], StdlibError> {
+    if args.len() != N {
+        return Err(StdlibError::wrong_args(fn_name, N, args.len()));
+    }
+    let mut out = [0.0; N];
+    for (i, arg) in args.iter().enumerate() {
+        out[i] = match arg {
+            Value::Number(n) => *n,
+            other => {
+                return Err(StdlibError::type_mismatch(
+                    fn_name,
+                    i + 1,
+                    "number",
+                    other.type_name(),
+                ));
+            }
+        };
+    }
+    Ok(out)
+}
+
 /// Guard against NaN results. Per PEPL spec: operations that would produce NaN
 /// trap instead.
 fn nan_guard(fn_name: &str, result: f64) -> Result<Value, StdlibError> {
@@ -141,6 +223,42 @@ fn nan_guard(fn_name: &str, result: f64) -> Result<Value, StdlibError> {
     }
 }
 
+/// Validate that `n` is a non-negative whole number (a count).
+fn expect_count(fn_name: &str, name: &str, n: f64) -> Result<f64, StdlibError> {
+    if n < 0.0 || n.fract() != 0.0 || !n.is_finite() {
+        return Err(StdlibError::RuntimeError(format!(
+            "{fn_name}: {name} must be a non-negative whole number, got {n}"
+        )));
+    }
+    Ok(n)
+}
+
+/// Shared body of `math.floor_to` / `math.ceil_to`: applies `direction` to
+/// `value / step` and scales back. A quotient within 1e-9 (relative) of a
+/// whole number counts as exact, so float noise like `0.3 / 0.1 = 2.999…`
+/// doesn't move an exact multiple by a whole step.
+fn round_to_step(
+    fn_name: &str,
+    args: &[Value],
+    direction: fn(f64) -> f64,
+) -> Result<Value, StdlibError> {
+    let (value, step) = expect_two_numbers(fn_name, args)?;
+    if step <= 0.0 || step.is_nan() {
+        return Err(StdlibError::RuntimeError(format!(
+            "{fn_name}: step must be positive"
+        )));
+    }
+    let quotient = value / step;
+    let nearest = quotient.round();
+    let steps = if (quotient - nearest).abs() <= 1e-9 * nearest.abs().max(1.0) {
+        nearest
+    } else {
+        direction(quotient)
+    };
+    // `+ 0.0` folds -0.0 into 0.0, matching `math.round`
+    nan_guard(fn_name, steps * step + 0.0)
+}
+
 // ── Function implementations ──────────────────────────────────────────────────
 
 impl MathModule {
@@ -199,6 +317,18 @@ impl MathModule {
         Ok(Value::Number((a + 0.5).floor()))
     }
 
+    /// `math.round_half_even(a: number) -> number`
+    ///
+    /// Round to nearest integer with ties going to the even neighbour
+    /// (banker's rounding): 0.5 → 0, 1.5 → 2, 2.5 → 2, -1.5 → -2. Prefer this
+    /// over `math.round` when summing or averaging many rounded values, since
+    /// "round half up" drifts upward; use `math.round` for display.
+    fn round_half_even(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let a = expect_one_number("math.round_half_even", &args)?;
+        // `+ 0.0` folds -0.0 (from -0.5) into 0.0, matching `math.round`.
+        Ok(Value::Number(a.round_ties_even() + 0.0))
+    }
+
     /// `math.round_to(a: number, decimals: number) -> number`
     ///
     /// Round to N decimal places using the same "0.5 rounds up" rule.
@@ -292,6 +422,195 @@ impl MathModule {
         Ok(Value::Number(a.sqrt()))
     }
 
+    /// `math.map_range(value, in_min, in_max, out_min, out_max) -> number`
+    ///
+    /// Proportionally remaps `value` from `[in_min, in_max]` to
+    /// `[out_min, out_max]`. The output range may be reversed. Values outside
+    /// the input range extrapolate (no clamping). Traps when `in_min == in_max`.
+    fn map_range(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let [value, in_min, in_max, out_min, out_max] =
+            expect_numbers::<5>("math.map_range", &args)?;
+        if in_min == in_max {
+            return Err(StdlibError::RuntimeError(
+                "math.map_range: input range has zero width (in_min == in_max)".to_string(),
+            ));
+        }
+        let t = (value - in_min) / (in_max - in_min);
+        nan_guard("math.map_range", out_min + t * (out_max - out_min))
+    }
+
+    /// `math.normalize(value: number, min: number, max: number) -> number`
+    ///
+    /// Where `value` sits between `min` and `max` as a fraction:
+    /// `(value - min) / (max - min)`, clamped to \[0, 1\] (a clamped inverse
+    /// lerp). Traps when `min == max`.
+    fn normalize(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let [value, min, max] = expect_numbers::<3>("math.normalize", &args)?;
+        if min == max {
+            return Err(StdlibError::RuntimeError(
+                "math.normalize: range has zero width (min == max)".to_string(),
+            ));
+        }
+        let t = (value - min) / (max - min);
+        nan_guard("math.normalize", t.clamp(0.0, 1.0))
+    }
+
+    /// `math.factorial(n: number) -> number`
+    ///
+    /// `n!` for a non-negative whole number. Anything past `170!` exceeds
+    /// f64 range and traps via `nan_guard`.
+    fn factorial(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let n = expect_one_number("math.factorial", &args)?;
+        let n = expect_count("math.factorial", "n", n)?;
+        let mut result = 1.0_f64;
+        let mut i = 2.0;
+        // Stop as soon as we overflow so huge inputs don't loop needlessly
+        while i <= n && result.is_finite() {
+            result *= i;
+            i += 1.0;
+        }
+        nan_guard("math.factorial", result)
+    }
+
+    /// `math.combinations(n: number, k: number) -> number`
+    ///
+    /// Binomial coefficient `C(n, k)`; 0 when `k > n`. Computed as a running
+    /// product over `min(k, n - k)` terms so intermediate factorials never
+    /// appear. Traps if the result itself exceeds f64 range.
+    fn combinations(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let (n, k) = expect_two_numbers("math.combinations", &args)?;
+        let n = expect_count("math.combinations", "n", n)?;
+        let k = expect_count("math.combinations", "k", k)?;
+        if k > n {
+            return Ok(Value::Number(0.0));
+        }
+        let k = k.min(n - k);
+        let mut result = 1.0_f64;
+        let mut i = 1.0;
+        while i <= k && result.is_finite() {
+            result = result * (n - k + i) / i;
+            i += 1.0;
+        }
+        nan_guard("math.combinations", result.round())
+    }
+
+    /// `math.is_close(a: number, b: number, rel_tol: number) -> bool`
+    ///
+    /// True when `|a - b| <= max(rel_tol * max(|a|, |b|), 1e-12)`. The
+    /// absolute floor keeps near-zero comparisons meaningful, where a purely
+    /// relative tolerance would collapse to exact equality. `rel_tol` must be
+    /// non-negative.
+    fn is_close(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let [a, b, rel_tol] = expect_numbers::<3>("math.is_close", &args)?;
+        if rel_tol < 0.0 || rel_tol.is_nan() {
+            return Err(StdlibError::RuntimeError(
+                "math.is_close: rel_tol must be non-negative".to_string(),
+            ));
+        }
+        if a == b {
+            return Ok(Value::Bool(true));
+        }
+        let tolerance = (rel_tol * a.abs().max(b.abs())).max(IS_CLOSE_ABS_TOL);
+        Ok(Value::Bool((a - b).abs() <= tolerance))
+    }
+
+    /// `math.clamp01(value: number) -> number`
+    ///
+    /// Clamp to the unit interval — shorthand for `clamp(value, 0, 1)`, handy
+    /// for alpha, progress and normalized color channels. Also available as
+    /// `math.saturate` (the shader-language name).
+    fn clamp01(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let value = expect_one_number("math.clamp01", &args)?;
+        Ok(Value::Number(value.clamp(0.0, 1.0)))
+    }
+
+    /// `math.degrees(radians: number) -> number`
+    ///
+    /// Convert radians to degrees (`× 180 / math.PI`).
+    fn degrees(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let radians = expect_one_number("math.degrees", &args)?;
+        nan_guard("math.degrees", radians * 180.0 / std::f64::consts::PI)
+    }
+
+    /// `math.radians(degrees: number) -> number`
+    ///
+    /// Convert degrees to radians (`× math.PI / 180`).
+    fn radians(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let degrees = expect_one_number("math.radians", &args)?;
+        nan_guard("math.radians", degrees * std::f64::consts::PI / 180.0)
+    }
+
+    /// `math.clamp_magnitude(value: number, max_abs: number) -> number`
+    ///
+    /// Returns `value` if `|value| <= max_abs`, otherwise `max_abs` with the
+    /// sign of `value`. Traps when `max_abs` is negative.
+    fn clamp_magnitude(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let (value, max_abs) = expect_two_numbers("math.clamp_magnitude", &args)?;
+        if max_abs < 0.0 || max_abs.is_nan() {
+            return Err(StdlibError::RuntimeError(
+                "math.clamp_magnitude: max_abs must be non-negative".to_string(),
+            ));
+        }
+        if value.abs() <= max_abs {
+            return Ok(Value::Number(value));
+        }
+        nan_guard("math.clamp_magnitude", max_abs.copysign(value))
+    }
+
+    /// `math.wrap(value: number, min: number, max: number) -> number`
+    ///
+    /// Maps `value` cyclically into `[min, max)` using Euclidean modulo, so
+    /// negatives wrap too: `wrap(-10, 0, 360)` → 350. Traps when
+    /// `min >= max`.
+    fn wrap(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let [value, min, max] = expect_numbers::<3>("math.wrap", &args)?;
+        if min >= max {
+            return Err(StdlibError::RuntimeError(
+                "math.wrap: min must be less than max".to_string(),
+            ));
+        }
+        let wrapped = min + (value - min).rem_euclid(max - min);
+        // rem_euclid can round up to exactly `max - min` for tiny negatives
+        let wrapped = if wrapped >= max { min } else { wrapped };
+        nan_guard("math.wrap", wrapped)
+    }
+
+    /// `math.snap_integer(value: number, tolerance: number) -> number`
+    ///
+    /// Returns the nearest integer when `value` is within `tolerance` of it
+    /// (e.g. `2.9999999` → `3`), otherwise `value` unchanged. Traps when
+    /// `tolerance` is negative.
+    fn snap_integer(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let (value, tolerance) = expect_two_numbers("math.snap_integer", &args)?;
+        if tolerance < 0.0 || tolerance.is_nan() {
+            return Err(StdlibError::RuntimeError(
+                "math.snap_integer: tolerance must be non-negative".to_string(),
+            ));
+        }
+        let nearest = value.round();
+        if (value - nearest).abs() <= tolerance {
+            // `+ 0.0` folds -0.0 into 0.0, matching `math.round`
+            return Ok(Value::Number(nearest + 0.0));
+        }
+        Ok(Value::Number(value))
+    }
+
+    /// `math.floor_to(value: number, step: number) -> number`
+    ///
+    /// Largest multiple of `step` that is `<= value` (e.g. `1.3` with step
+    /// `0.25` → `1.25`). Traps when `step <= 0`.
+    fn floor_to(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        round_to_step("math.floor_to", &args, f64::floor)
+    }
+
+    /// `math.ceil_to(value: number, step: number) -> number`
+    ///
+    /// Smallest multiple of `step` that is `>= value` (e.g. `1.3` with step
+    /// `0.25` → `1.5`). Traps when `step <= 0`.
+    fn ceil_to(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        round_to_step("math.ceil_to", &args, f64::ceil)
+    }
+
     /// `math.PI` constant — 3.14159265358979…
     fn pi(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
         if !args.is_empty() {
@@ -6,14 +6,18 @@
 
 pub mod convert;
 pub mod core;
+pub mod csv;
+pub mod encoding;
 pub mod http;
 pub mod json;
 pub mod list;
 pub mod location;
 pub mod math;
 pub mod notifications;
+pub mod path;
 pub mod record;
 pub mod storage;
 pub mod string;
 pub mod time;
 pub mod timer;
+pub mod validate;
@@ -14,7 +14,7 @@
 
 use crate::capability::{CAP_NOTIFICATIONS, NOTIFICATIONS_SEND};
 use crate::error::StdlibError;
-use crate::module::StdlibModule;
+use crate::module::{Arity, StdlibModule};
 use crate::value::Value;
 
 /// The `notifications` capability module.
@@ -47,6 +47,13 @@ impl StdlibModule for NotificationsModule {
             _ => Err(StdlibError::unknown_function("notifications", function)),
         }
     }
+
+    fn arity(&self, function: &str) -> Option<Arity> {
+        Some(match function {
+            "send" => Arity::Exact(2),
+            _ => return None,
+        })
+    }
 }
 
 impl NotificationsModule {
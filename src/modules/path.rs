@@ -0,0 +1,196 @@
+//! `path` stdlib module — `/`-separated path manipulation.
+//!
+//! Functions: join, basename, dirname, extension, split.
+//! Paths are plain strings (e.g. storage keys); nothing touches a filesystem.
+//! `/` is the only separator. Trailing slashes are ignored when finding the
+//! last segment, so `"a/b/"` behaves like `"a/b"` for `basename`/`dirname`.
+
+use crate::error::StdlibError;
+use crate::module::{Arity, StdlibModule};
+use crate::value::Value;
+
+/// The `path` stdlib module.
+pub struct PathModule;
+
+impl PathModule {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for PathModule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StdlibModule for PathModule {
+    fn name(&self) -> &'static str {
+        "path"
+    }
+
+    fn has_function(&self, function: &str) -> bool {
+        matches!(
+            function,
+            "join" | "basename" | "dirname" | "extension" | "split"
+        )
+    }
+
+    fn call(&self, function: &str, args: Vec<Value>) -> Result<Value, StdlibError> {
+        match function {
+            "join" => self.join(args),
+            "basename" => self.basename(args),
+            "dirname" => self.dirname(args),
+            "extension" => self.extension(args),
+            "split" => self.split(args),
+            _ => Err(StdlibError::unknown_function("path", function)),
+        }
+    }
+
+    fn arity(&self, function: &str) -> Option<Arity> {
+        Some(match function {
+            "join" | "basename" | "dirname" | "extension" | "split" => Arity::Exact(1),
+            _ => return None,
+        })
+    }
+}
+
+impl PathModule {
+    /// path.join(parts) → string
+    /// Joins parts with exactly one `/` between them, ignoring empty parts and
+    /// collapsing slashes at each join. A leading `/` on the first part and a
+    /// trailing `/` on the last are kept: `["/a/", "b/"]` → `"/a/b/"`.
+    fn join(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 1 {
+            return Err(StdlibError::wrong_args("path.join", 1, args.len()));
+        }
+        let parts = extract_string_list("path.join", &args[0], 1)?;
+        let parts: Vec<&str> = parts.into_iter().filter(|p| !p.is_empty()).collect();
+        let last = parts.len().saturating_sub(1);
+        let mut result = String::new();
+        for (i, part) in parts.iter().enumerate() {
+            let part = if i == 0 {
+                part
+            } else {
+                part.trim_start_matches('/')
+            };
+            let part = if i == last {
+                part
+            } else {
+                part.trim_end_matches('/')
+            };
+            if i > 0 && !result.ends_with('/') {
+                result.push('/');
+            }
+            result.push_str(part);
+        }
+        Ok(Value::String(result))
+    }
+
+    /// path.basename(p) → string
+    /// The last segment: `"a/b.txt"` → `"b.txt"`, `"a/b/"` → `"b"`.
+    /// Returns `""` for `""` and `"/"`.
+    fn basename(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let p = expect_one_string("path.basename", &args)?;
+        Ok(Value::String(split_last(p).1.to_string()))
+    }
+
+    /// path.dirname(p) → string
+    /// Everything before the last segment, without a trailing slash:
+    /// `"a/b/c"` → `"a/b"`, `"/a"` → `"/"`. Returns `""` when there is no
+    /// directory part (`"a"`, `""`).
+    fn dirname(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let p = expect_one_string("path.dirname", &args)?;
+        Ok(Value::String(split_last(p).0.to_string()))
+    }
+
+    /// path.extension(p) → string|nil
+    /// The basename's extension without the dot: `"a/b.tar.gz"` → `"gz"`.
+    /// Nil when there is none, including dotfiles (`".env"`) and a trailing
+    /// dot (`"file."`).
+    fn extension(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let p = expect_one_string("path.extension", &args)?;
+        let name = split_last(p).1;
+        Ok(match name.rfind('.') {
+            Some(i) if i > 0 && i + 1 < name.len() => Value::String(name[i + 1..].to_string()),
+            _ => Value::Nil,
+        })
+    }
+
+    /// path.split(p) → list<string>
+    /// The non-empty segments: `"/a//b/"` → `["a", "b"]`. Inverse of
+    /// `path.join` for relative paths without empty segments.
+    fn split(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let p = expect_one_string("path.split", &args)?;
+        let segments = p
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .map(|s| Value::String(s.to_string()))
+            .collect();
+        Ok(Value::List(segments))
+    }
+}
+
+// ── Helpers ──────────────────────────────────────────────────────────────────
+
+/// Split into (dirname, basename), ignoring trailing slashes.
+fn split_last(p: &str) -> (&str, &str) {
+    let trimmed = p.trim_end_matches('/');
+    if trimmed.is_empty() {
+        // "" or all slashes
+        let dir = if p.is_empty() { "" } else { "/" };
+        return (dir, "");
+    }
+    match trimmed.rfind('/') {
+        Some(i) => {
+            let dir = trimmed[..i].trim_end_matches('/');
+            let dir = if dir.is_empty() { "/" } else { dir };
+            (dir, &trimmed[i + 1..])
+        }
+        None => ("", trimmed),
+    }
+}
+
+fn expect_one_string<'a>(func: &str, args: &'a [Value]) -> Result<&'a str, StdlibError> {
+    if args.len() != 1 {
+        return Err(StdlibError::wrong_args(func, 1, args.len()));
+    }
+    match &args[0] {
+        Value::String(s) => Ok(s),
+        other => Err(StdlibError::type_mismatch(
+            func,
+            1,
+            "string",
+            other.type_name(),
+        )),
+    }
+}
+
+fn extract_string_list<'a>(
+    func: &str,
+    val: &'a Value,
+    pos: usize,
+) -> Result<Vec<&'a str>, StdlibError> {
+    let items = match val {
+        Value::List(items) => items,
+        _ => {
+            return Err(StdlibError::type_mismatch(
+                func,
+                pos,
+                "list",
+                val.type_name(),
+            ))
+        }
+    };
+    items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| match item {
+            Value::String(s) => Ok(s.as_str()),
+            other => Err(StdlibError::RuntimeError(format!(
+                "{func}: element {i} must be a string, got {}",
+                other.type_name()
+            ))),
+        })
+        .collect()
+}
@@ -1,12 +1,13 @@
 //! `record` stdlib module — immutable record operations.
 //!
-//! Functions: get, set, has, keys, values.
+//! Functions: get, set, has, keys, values, deep_get, deep_set, rename_key,
+//! equals_ignoring, to_string_pairs, map_keys.
 
 use std::collections::BTreeMap;
 
 use crate::error::StdlibError;
-use crate::module::StdlibModule;
-use crate::value::Value;
+use crate::module::{Arity, StdlibModule};
+use crate::value::{StdlibFn, Value};
 
 /// The `record` stdlib module.
 pub struct RecordModule;
@@ -29,7 +30,20 @@ impl StdlibModule for RecordModule {
     }
 
     fn has_function(&self, function: &str) -> bool {
-        matches!(function, "get" | "set" | "has" | "keys" | "values")
+        matches!(
+            function,
+            "get"
+                | "set"
+                | "has"
+                | "keys"
+                | "values"
+                | "deep_get"
+                | "deep_set"
+                | "rename_key"
+                | "equals_ignoring"
+                | "to_string_pairs"
+                | "map_keys"
+        )
     }
 
     fn call(&self, function: &str, args: Vec<Value>) -> Result<Value, StdlibError> {
@@ -39,9 +53,24 @@ impl StdlibModule for RecordModule {
             "has" => self.has(args),
             "keys" => self.keys(args),
             "values" => self.values(args),
+            "deep_get" => self.deep_get(args),
+            "deep_set" => self.deep_set(args),
+            "rename_key" => self.rename_key(args),
+            "equals_ignoring" => self.equals_ignoring(args),
+            "to_string_pairs" => self.to_string_pairs(args),
+            "map_keys" => self.map_keys(args),
             _ => Err(StdlibError::unknown_function("record", function)),
         }
     }
+
+    fn arity(&self, function: &str) -> Option<Arity> {
+        Some(match function {
+            "get" | "has" | "deep_get" | "map_keys" => Arity::Exact(2),
+            "set" | "deep_set" | "rename_key" | "equals_ignoring" => Arity::Exact(3),
+            "keys" | "values" | "to_string_pairs" => Arity::Exact(1),
+            _ => return None,
+        })
+    }
 }
 
 impl RecordModule {
@@ -100,6 +129,175 @@ impl RecordModule {
         let values: Vec<Value> = fields.values().cloned().collect();
         Ok(Value::List(values))
     }
+
+    /// record.deep_get(rec, path) → any
+    /// Follows `path` (a list of keys) through nested records. Returns Nil if
+    /// any segment is missing or an intermediate value is not a record.
+    fn deep_get(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 2 {
+            return Err(StdlibError::wrong_args("record.deep_get", 2, args.len()));
+        }
+        extract_record("record.deep_get", &args[0], 1)?;
+        let path = extract_path("record.deep_get", &args[1], 2)?;
+        let mut current = &args[0];
+        for key in path {
+            match current {
+                Value::Record { fields, .. } => match fields.get(key) {
+                    Some(next) => current = next,
+                    None => return Ok(Value::Nil),
+                },
+                _ => return Ok(Value::Nil),
+            }
+        }
+        Ok(current.clone())
+    }
+
+    /// record.deep_set(rec, path, value) → record
+    /// Returns a new record with the leaf at `path` set to `value`. Every
+    /// intermediate record is copied; missing segments are created as empty
+    /// records. Traps if the path is empty or runs through a non-record value.
+    fn deep_set(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 3 {
+            return Err(StdlibError::wrong_args("record.deep_set", 3, args.len()));
+        }
+        let fields = extract_record("record.deep_set", &args[0], 1)?;
+        let path = extract_path("record.deep_set", &args[1], 2)?;
+        if path.is_empty() {
+            return Err(StdlibError::RuntimeError(
+                "record.deep_set: path must not be empty".to_string(),
+            ));
+        }
+        let new_fields = set_path(fields, &path, args[2].clone())?;
+        Ok(Value::record(new_fields))
+    }
+
+    /// record.rename_key(rec, old, new) → record
+    /// Returns a new record with `old` renamed to `new`, keeping its value and
+    /// overwriting any existing `new`. Fields stay in key order, so the renamed
+    /// field sorts by its new name. Returns the record unchanged if `old` is
+    /// absent.
+    fn rename_key(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 3 {
+            return Err(StdlibError::wrong_args("record.rename_key", 3, args.len()));
+        }
+        let fields = extract_record("record.rename_key", &args[0], 1)?;
+        let old = extract_string("record.rename_key", &args[1], 2)?;
+        let new = extract_string("record.rename_key", &args[2], 3)?;
+        if old == new || !fields.contains_key(old) {
+            return Ok(args[0].clone());
+        }
+        let mut new_fields = fields.clone();
+        if let Some(value) = new_fields.remove(old) {
+            new_fields.insert(new.to_string(), value);
+        }
+        Ok(Value::record(new_fields))
+    }
+
+    /// record.equals_ignoring(a, b, ignore_keys) → bool
+    /// Structural equality of `a` and `b` after dropping `ignore_keys` from
+    /// both. A key present on only one side makes them unequal unless it is
+    /// ignored. Type names are not compared, as with `==`.
+    fn equals_ignoring(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 3 {
+            return Err(StdlibError::wrong_args(
+                "record.equals_ignoring",
+                3,
+                args.len(),
+            ));
+        }
+        let a = extract_record("record.equals_ignoring", &args[0], 1)?;
+        let b = extract_record("record.equals_ignoring", &args[1], 2)?;
+        let ignore = extract_path("record.equals_ignoring", &args[2], 3)?;
+        let kept = |fields: &BTreeMap<String, Value>| -> BTreeMap<String, Value> {
+            fields
+                .iter()
+                .filter(|(key, _)| !ignore.contains(&key.as_str()))
+                .map(|(key, val)| (key.clone(), val.clone()))
+                .collect()
+        };
+        Ok(Value::Bool(kept(a) == kept(b)))
+    }
+
+    /// record.to_string_pairs(rec) → list<record>
+    /// One `{key, value}` record per field in BTreeMap order, with `value`
+    /// rendered to a string via Display (`1` → `"1"`, `true` → `"true"`).
+    /// Suited to building URL query strings.
+    fn to_string_pairs(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 1 {
+            return Err(StdlibError::wrong_args(
+                "record.to_string_pairs",
+                1,
+                args.len(),
+            ));
+        }
+        let fields = extract_record("record.to_string_pairs", &args[0], 1)?;
+        let pairs = fields
+            .iter()
+            .map(|(key, val)| {
+                Value::record_of(&[
+                    ("key", Value::String(key.clone())),
+                    ("value", Value::String(val.to_string())),
+                ])
+            })
+            .collect();
+        Ok(Value::List(pairs))
+    }
+
+    /// record.map_keys(rec, f) → record
+    /// Returns a new anonymous record with every key replaced by `f(key)`,
+    /// which must return a string. Values are unchanged. Keys are visited in
+    /// BTreeMap order, so when two keys map to the same name the later one
+    /// wins.
+    fn map_keys(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 2 {
+            return Err(StdlibError::wrong_args("record.map_keys", 2, args.len()));
+        }
+        let fields = extract_record("record.map_keys", &args[0], 1)?;
+        let f = extract_function("record.map_keys", &args[1], 2)?;
+        check_arity("record.map_keys", &f, 1)?;
+        let mut mapped = BTreeMap::new();
+        for (key, val) in fields {
+            match f.call(vec![Value::String(key.clone())])? {
+                Value::String(new_key) => {
+                    mapped.insert(new_key, val.clone());
+                }
+                other => {
+                    return Err(StdlibError::RuntimeError(format!(
+                        "record.map_keys: f must return a string, got {}",
+                        other.type_name()
+                    )))
+                }
+            }
+        }
+        Ok(Value::record(mapped))
+    }
+}
+
+/// Recursively copy `fields`, replacing the value at `path` (non-empty).
+fn set_path(
+    fields: &BTreeMap<String, Value>,
+    path: &[&str],
+    value: Value,
+) -> Result<BTreeMap<String, Value>, StdlibError> {
+    let mut new_fields = fields.clone();
+    let (key, rest) = (path[0], &path[1..]);
+    if rest.is_empty() {
+        new_fields.insert(key.to_string(), value);
+        return Ok(new_fields);
+    }
+    let child = match fields.get(key) {
+        Some(Value::Record { fields: inner, .. }) => set_path(inner, rest, value)?,
+        None => set_path(&BTreeMap::new(), rest, value)?,
+        Some(other) => {
+            return Err(StdlibError::RuntimeError(format!(
+                "record.deep_set: cannot descend into '{}' ({})",
+                key,
+                other.type_name()
+            )));
+        }
+    };
+    new_fields.insert(key.to_string(), Value::record(child));
+    Ok(new_fields)
 }
 
 // ── Helpers ──────────────────────────────────────────────────────────────────
@@ -120,6 +318,32 @@ fn extract_record<'a>(
     }
 }
 
+/// Extract a `list<string>` key path (or key set).
+fn extract_path<'a>(func: &str, val: &'a Value, pos: usize) -> Result<Vec<&'a str>, StdlibError> {
+    let items = match val {
+        Value::List(items) => items,
+        _ => {
+            return Err(StdlibError::type_mismatch(
+                func,
+                pos,
+                "list",
+                val.type_name(),
+            ))
+        }
+    };
+    items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| match item {
+            Value::String(s) => Ok(s.as_str()),
+            other => Err(StdlibError::RuntimeError(format!(
+                "{func}: path segment {i} must be a string, got {}",
+                other.type_name()
+            ))),
+        })
+        .collect()
+}
+
 fn extract_string<'a>(func: &str, val: &'a Value, pos: usize) -> Result<&'a str, StdlibError> {
     match val {
         Value::String(s) => Ok(s),
@@ -131,3 +355,27 @@ fn extract_string<'a>(func: &str, val: &'a Value, pos: usize) -> Result<&'a str,
         )),
     }
 }
+
+fn extract_function(func: &str, val: &Value, pos: usize) -> Result<StdlibFn, StdlibError> {
+    match val {
+        Value::Function(f) => Ok(f.clone()),
+        _ => Err(StdlibError::type_mismatch(
+            func,
+            pos,
+            "function",
+            val.type_name(),
+        )),
+    }
+}
+
+/// Reject a callback whose declared arity differs from what `func` will pass
+/// it. Callbacks without a declared arity are always accepted.
+fn check_arity(func: &str, f: &StdlibFn, expected: usize) -> Result<(), StdlibError> {
+    match f.arity() {
+        Some(arity) if arity != expected => Err(StdlibError::RuntimeError(format!(
+            "{func}: callback takes {arity} argument{}, but {func} calls it with {expected}",
+            if arity == 1 { "" } else { "s" }
+        ))),
+        _ => Ok(()),
+    }
+}
@@ -1,6 +1,6 @@
 //! `storage` capability module — persistent key-value storage (host-delegated).
 //!
-//! Functions: get, set, delete, keys.
+//! Functions: get, set, delete, keys, get_all.
 //! All storage operations are host-delegated — the runtime host manages actual
 //! persistence via `env.host_call(cap_id=2, fn_id, payload)`. This module
 //! validates arguments and returns `CapabilityCall` errors to signal the
@@ -14,10 +14,13 @@
 //! | 2     | set      |
 //! | 3     | delete   |
 //! | 4     | keys     |
+//! | 7     | get_all  |
 
-use crate::capability::{CAP_STORAGE, STORAGE_DELETE, STORAGE_GET, STORAGE_KEYS, STORAGE_SET};
+use crate::capability::{
+    CAP_STORAGE, STORAGE_DELETE, STORAGE_GET, STORAGE_GET_ALL, STORAGE_KEYS, STORAGE_SET,
+};
 use crate::error::StdlibError;
-use crate::module::StdlibModule;
+use crate::module::{Arity, StdlibModule};
 use crate::value::Value;
 
 /// The `storage` capability module.
@@ -41,7 +44,7 @@ impl StdlibModule for StorageModule {
     }
 
     fn has_function(&self, function: &str) -> bool {
-        matches!(function, "get" | "set" | "delete" | "keys")
+        matches!(function, "get" | "set" | "delete" | "keys" | "get_all")
     }
 
     fn call(&self, function: &str, args: Vec<Value>) -> Result<Value, StdlibError> {
@@ -50,9 +53,19 @@ impl StdlibModule for StorageModule {
             "set" => self.set(args),
             "delete" => self.delete(args),
             "keys" => self.keys(args),
+            "get_all" => self.get_all(args),
             _ => Err(StdlibError::unknown_function("storage", function)),
         }
     }
+
+    fn arity(&self, function: &str) -> Option<Arity> {
+        Some(match function {
+            "get" | "delete" => Arity::Exact(1),
+            "set" => Arity::Exact(2),
+            "keys" | "get_all" => Arity::Exact(0),
+            _ => return None,
+        })
+    }
 }
 
 impl StorageModule {
@@ -127,6 +140,24 @@ impl StorageModule {
             args,
         ))
     }
+
+    /// `storage.get_all() -> Result<record, StorageError>`
+    ///
+    /// Every stored key/value pair as one record, saving a round-trip per key.
+    /// Validates: no args.
+    /// Returns `CapabilityCall` with cap_id=2, fn_id=7.
+    fn get_all(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if !args.is_empty() {
+            return Err(StdlibError::wrong_args("storage.get_all", 0, args.len()));
+        }
+        Err(StdlibError::capability_call(
+            "storage",
+            "get_all",
+            CAP_STORAGE,
+            STORAGE_GET_ALL,
+            args,
+        ))
+    }
 }
 
 // ── Helpers ──────────────────────────────────────────────────────────────────
@@ -1,4 +1,4 @@
-//! The `string` module — 20 functions.
+//! The `string` module — 32 functions.
 //!
 //! | Function           | Signature                                              | Description                      |
 //! |--------------------|--------------------------------------------------------|----------------------------------|
@@ -22,11 +22,27 @@
 //! | `string.from`      | `(value: any) -> string`                               | Any value to string              |
 //! | `string.is_empty`  | `(s: string) -> bool`                                  | True if zero length              |
 //! | `string.index_of`  | `(s: string, sub: string) -> number`                   | Index of sub, or -1              |
+//! | `string.normalize_whitespace` | `(s: string) -> string`                 | Trim and collapse WS runs        |
+//! | `string.count_words` | `(s: string) -> number`                             | Count whitespace-separated words |
+//! | `string.split_n`   | `(s: string, delimiter: string, limit: number) -> list<string>` | Split into at most N parts |
+//! | `string.char_indices` | `(s: string) -> list<record>`                     | `{char, byte_index, char_index}` per char |
+//! | `string.interleave` | `(a: string, b: string) -> string`                   | Alternate chars of a and b       |
+//! | `string.strip_prefix` | `(s: string, prefix: string) -> string`           | Remove prefix if present         |
+//! | `string.strip_suffix` | `(s: string, suffix: string) -> string`           | Remove suffix if present         |
+//! | `string.pad_number` | `(n: number, width: number) -> string`             | Right-align number in a field    |
+//! | `string.is_blank`  | `(s: string) -> bool`                                  | True if empty or only whitespace |
+//! | `string.indent`    | `(s: string, prefix: string) -> string`                | Prefix every non-empty line      |
+//! | `string.common_prefix` | `(a: string, b: string) -> string`                 | Longest shared leading run       |
+//! | `string.common_suffix` | `(a: string, b: string) -> string`                 | Longest shared trailing run      |
 
 use crate::error::StdlibError;
-use crate::module::StdlibModule;
+use crate::module::{Arity, StdlibModule};
 use crate::value::Value;
 
+/// Largest `width` accepted by `string.pad_number`. Bounds the padding
+/// allocation; wider fields trap instead of exhausting memory.
+const MAX_PAD_WIDTH: f64 = 1_000_000.0;
+
 /// The `string` stdlib module.
 pub struct StringModule;
 
@@ -70,6 +86,18 @@ impl StdlibModule for StringModule {
                 | "from"
                 | "is_empty"
                 | "index_of"
+                | "normalize_whitespace"
+                | "count_words"
+                | "split_n"
+                | "char_indices"
+                | "interleave"
+                | "strip_prefix"
+                | "strip_suffix"
+                | "pad_number"
+                | "is_blank"
+                | "indent"
+                | "common_prefix"
+                | "common_suffix"
         )
     }
 
@@ -95,9 +123,43 @@ impl StdlibModule for StringModule {
             "from" => self.value_to_string(args),
             "is_empty" => self.is_empty(args),
             "index_of" => self.index_of(args),
+            "normalize_whitespace" => self.normalize_whitespace(args),
+            "count_words" => self.count_words(args),
+            "split_n" => self.split_n(args),
+            "char_indices" => self.char_indices(args),
+            "interleave" => self.interleave(args),
+            "strip_prefix" => self.strip_prefix(args),
+            "strip_suffix" => self.strip_suffix(args),
+            "pad_number" => self.pad_number(args),
+            "is_blank" => self.is_blank(args),
+            "indent" => self.indent(args),
+            "common_prefix" => self.common_prefix(args),
+            "common_suffix" => self.common_suffix(args),
             _ => Err(StdlibError::unknown_function("string", function)),
         }
     }
+
+    fn arity(&self, function: &str) -> Option<Arity> {
+        Some(match function {
+            "length"
+            | "trim"
+            | "to_upper"
+            | "to_lower"
+            | "from"
+            | "is_empty"
+            | "normalize_whitespace"
+            | "count_words"
+            | "char_indices"
+            | "is_blank" => Arity::Exact(1),
+            "concat" | "contains" | "split" | "starts_with" | "ends_with" | "repeat" | "join"
+            | "format" | "index_of" | "interleave" | "strip_prefix" | "strip_suffix"
+            | "pad_number" | "indent" | "common_prefix" | "common_suffix" => Arity::Exact(2),
+            "slice" | "replace" | "replace_all" | "pad_start" | "pad_end" | "split_n" => {
+                Arity::Exact(3)
+            }
+            _ => return None,
+        })
+    }
 }
 
 // ── Helpers ───────────────────────────────────────────────────────────────────
@@ -192,6 +254,32 @@ fn expect_three_strings(
     Ok((a, b, c))
 }
 
+/// Extract a string argument at a given position.
+fn extract_string(fn_name: &str, val: &Value, position: usize) -> Result<String, StdlibError> {
+    match val {
+        Value::String(s) => Ok(s.clone()),
+        other => Err(StdlibError::type_mismatch(
+            fn_name,
+            position,
+            "string",
+            other.type_name(),
+        )),
+    }
+}
+
+/// Extract a number argument at a given position.
+fn extract_number(fn_name: &str, val: &Value, position: usize) -> Result<f64, StdlibError> {
+    match val {
+        Value::Number(n) => Ok(*n),
+        other => Err(StdlibError::type_mismatch(
+            fn_name,
+            position,
+            "number",
+            other.type_name(),
+        )),
+    }
+}
+
 // ── Function implementations ──────────────────────────────────────────────────
 
 impl StringModule {
@@ -605,4 +693,213 @@ impl StringModule {
             None => Ok(Value::Number(-1.0)),
         }
     }
+
+    /// `string.normalize_whitespace(s: string) -> string`
+    ///
+    /// Trims the string and collapses every run of internal whitespace
+    /// (spaces, tabs, newlines) into a single space.
+    fn normalize_whitespace(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let s = expect_one_string("string.normalize_whitespace", &args)?;
+        let words: Vec<&str> = s.split_whitespace().collect();
+        Ok(Value::String(words.join(" ")))
+    }
+
+    /// `string.count_words(s: string) -> number`
+    ///
+    /// Counts maximal runs of non-whitespace characters, so repeated spaces
+    /// don't inflate the count. Empty or all-whitespace strings return 0.
+    fn count_words(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let s = expect_one_string("string.count_words", &args)?;
+        Ok(Value::Number(s.split_whitespace().count() as f64))
+    }
+
+    /// `string.split_n(s: string, delimiter: string, limit: number) -> list<string>`
+    ///
+    /// Like `split`, but produces at most `limit` parts; the last part keeps
+    /// any remaining delimiters (Rust's `splitn`). An empty delimiter splits
+    /// into characters, as `split` does. `limit` must be a positive integer.
+    fn split_n(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 3 {
+            return Err(StdlibError::wrong_args("string.split_n", 3, args.len()));
+        }
+        let s = extract_string("string.split_n", &args[0], 1)?;
+        let delimiter = extract_string("string.split_n", &args[1], 2)?;
+        let limit = extract_number("string.split_n", &args[2], 3)?;
+        if limit < 1.0 || limit.fract() != 0.0 || !limit.is_finite() {
+            return Err(StdlibError::RuntimeError(
+                "string.split_n: limit must be a positive integer".to_string(),
+            ));
+        }
+        let limit = limit as usize;
+
+        let parts: Vec<Value> = if delimiter.is_empty() {
+            let mut chars = s.chars();
+            let mut parts: Vec<Value> = chars
+                .by_ref()
+                .take(limit - 1)
+                .map(|c| Value::String(c.to_string()))
+                .collect();
+            let rest: String = chars.collect();
+            if !rest.is_empty() {
+                parts.push(Value::String(rest));
+            }
+            parts
+        } else {
+            s.splitn(limit, delimiter.as_str())
+                .map(|part| Value::String(part.to_string()))
+                .collect()
+        };
+        Ok(Value::List(parts))
+    }
+
+    /// `string.char_indices(s: string) -> list<record>`
+    ///
+    /// One `{char, byte_index, char_index}` record per Unicode scalar value.
+    /// `byte_index` is the UTF-8 byte offset; `char_index` is the position
+    /// used by `slice`, `length` and `index_of`.
+    fn char_indices(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let s = expect_one_string("string.char_indices", &args)?;
+        let records = s
+            .char_indices()
+            .enumerate()
+            .map(|(char_index, (byte_index, c))| {
+                Value::record_of(&[
+                    ("char", Value::String(c.to_string())),
+                    ("byte_index", Value::Number(byte_index as f64)),
+                    ("char_index", Value::Number(char_index as f64)),
+                ])
+            })
+            .collect();
+        Ok(Value::List(records))
+    }
+
+    /// `string.interleave(a: string, b: string) -> string`
+    ///
+    /// Alternates characters of `a` and `b` (`a[0]`, `b[0]`, `a[1]`, …), then
+    /// appends the rest of the longer string. Works on Unicode scalar values.
+    fn interleave(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 2 {
+            return Err(StdlibError::wrong_args("string.interleave", 2, args.len()));
+        }
+        let a = extract_string("string.interleave", &args[0], 1)?;
+        let b = extract_string("string.interleave", &args[1], 2)?;
+        let mut result = String::with_capacity(a.len() + b.len());
+        let (mut a_chars, mut b_chars) = (a.chars(), b.chars());
+        loop {
+            match (a_chars.next(), b_chars.next()) {
+                (None, None) => break,
+                (x, y) => result.extend(x.into_iter().chain(y)),
+            }
+        }
+        Ok(Value::String(result))
+    }
+
+    /// `string.strip_prefix(s: string, prefix: string) -> string`
+    ///
+    /// Removes `prefix` once if `s` starts with it; otherwise returns `s`
+    /// unchanged.
+    fn strip_prefix(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let (s, prefix) = expect_two_strings("string.strip_prefix", &args)?;
+        let stripped = s.strip_prefix(prefix.as_str()).unwrap_or(&s);
+        Ok(Value::String(stripped.to_string()))
+    }
+
+    /// `string.strip_suffix(s: string, suffix: string) -> string`
+    ///
+    /// Removes `suffix` once if `s` ends with it; otherwise returns `s`
+    /// unchanged.
+    fn strip_suffix(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let (s, suffix) = expect_two_strings("string.strip_suffix", &args)?;
+        let stripped = s.strip_suffix(suffix.as_str()).unwrap_or(&s);
+        Ok(Value::String(stripped.to_string()))
+    }
+
+    /// `string.pad_number(n: number, width: number) -> string`
+    ///
+    /// Renders `n` with the usual `Value` display rules and left-pads it with
+    /// spaces to `width` characters. Never truncates: a number wider than the
+    /// field is returned as-is.
+    /// `width` must be a whole number from 0 to 1,000,000.
+    fn pad_number(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 2 {
+            return Err(StdlibError::wrong_args("string.pad_number", 2, args.len()));
+        }
+        let n = extract_number("string.pad_number", &args[0], 1)?;
+        let width = extract_number("string.pad_number", &args[1], 2)?;
+        if width < 0.0 || width.fract() != 0.0 {
+            return Err(StdlibError::RuntimeError(
+                "string.pad_number: width must be a non-negative integer".to_string(),
+            ));
+        }
+        if width > MAX_PAD_WIDTH {
+            return Err(StdlibError::RuntimeError(format!(
+                "string.pad_number: width must be at most {MAX_PAD_WIDTH}, got {width}"
+            )));
+        }
+        let rendered = Value::Number(n).to_string();
+        // Pad by hand like `pad_start`: std's `{:>width$}` panics for widths
+        // above u16::MAX
+        let needed = (width as usize).saturating_sub(rendered.chars().count());
+        Ok(Value::String(format!("{}{rendered}", " ".repeat(needed))))
+    }
+
+    /// `string.is_blank(s: string) -> bool`
+    ///
+    /// True if `s` is empty or contains only (Unicode) whitespace. Unlike
+    /// `string.is_empty`, `"   "` counts as blank.
+    fn is_blank(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let s = expect_one_string("string.is_blank", &args)?;
+        Ok(Value::Bool(s.chars().all(char::is_whitespace)))
+    }
+
+    /// `string.indent(s: string, prefix: string) -> string`
+    ///
+    /// Prepends `prefix` to every line of `s`. Line endings (`\n` or `\r\n`),
+    /// including a trailing one, are preserved as-is. Empty lines are left
+    /// unindented so the output carries no trailing whitespace; lines holding
+    /// only spaces still count as non-empty.
+    fn indent(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let (s, prefix) = expect_two_strings("string.indent", &args)?;
+        let mut out = String::with_capacity(s.len());
+        for line in s.split_inclusive('\n') {
+            let content = line.trim_end_matches('\n').trim_end_matches('\r');
+            if !content.is_empty() {
+                out.push_str(&prefix);
+            }
+            out.push_str(line);
+        }
+        Ok(Value::String(out))
+    }
+
+    /// `string.common_prefix(a: string, b: string) -> string`
+    ///
+    /// Longest run of leading characters shared by `a` and `b`. Compares by
+    /// Unicode scalar value, so the result is always valid UTF-8 but may end
+    /// between a base character and a following combining mark.
+    fn common_prefix(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let (a, b) = expect_two_strings("string.common_prefix", &args)?;
+        let len: usize = a
+            .chars()
+            .zip(b.chars())
+            .take_while(|(x, y)| x == y)
+            .map(|(c, _)| c.len_utf8())
+            .sum();
+        Ok(Value::String(a[..len].to_string()))
+    }
+
+    /// `string.common_suffix(a: string, b: string) -> string`
+    ///
+    /// Longest run of trailing characters shared by `a` and `b`, compared by
+    /// Unicode scalar value like `string.common_prefix`.
+    fn common_suffix(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let (a, b) = expect_two_strings("string.common_suffix", &args)?;
+        let len: usize = a
+            .chars()
+            .rev()
+            .zip(b.chars().rev())
+            .take_while(|(x, y)| x == y)
+            .map(|(c, _)| c.len_utf8())
+            .sum();
+        Ok(Value::String(a[a.len() - len..].to_string()))
+    }
 }
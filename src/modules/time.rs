@@ -1,23 +1,45 @@
 //! `time` stdlib module — timestamp operations.
 //!
 //! All timestamps are milliseconds since Unix epoch as f64.
-//! Functions: now, format, diff, day_of_week, start_of_day.
+//! Functions: now, format, diff, day_of_week, start_of_day, relative,
+//! duration_format, between, quarter, week_of_year.
 
 use crate::error::StdlibError;
-use crate::module::StdlibModule;
+use crate::module::{Arity, StdlibModule};
 use crate::value::Value;
 
 /// Milliseconds per day.
 const MS_PER_DAY: f64 = 86_400_000.0;
+/// Milliseconds per hour.
+const MS_PER_HOUR: f64 = 3_600_000.0;
+/// Milliseconds per minute.
+const MS_PER_MINUTE: f64 = 60_000.0;
 /// Milliseconds per second.
 const MS_PER_SECOND: f64 = 1_000.0;
 
 /// The `time` stdlib module.
-pub struct TimeModule;
+///
+/// `time.now` returns a fixed timestamp per instance: 0 by default, or the
+/// value injected via [`TimeModule::with_now`]. Keeping it fixed preserves
+/// determinism — the same instance always reports the same time.
+///
+/// **Breaking change:** this used to be a unit struct. It now holds the
+/// injected clock in a private field, so the bare `TimeModule` value no
+/// longer compiles. Construct it with [`TimeModule::new`] (or `Default`) or
+/// [`TimeModule::with_now`].
+pub struct TimeModule {
+    now: f64,
+}
 
 impl TimeModule {
     pub fn new() -> Self {
-        Self
+        Self::with_now(0.0)
+    }
+
+    /// Create a module whose `time.now` always returns `now` (ms since epoch).
+    /// Useful for hosts and test harnesses that need a specific clock value.
+    pub fn with_now(now: f64) -> Self {
+        Self { now }
     }
 }
 
@@ -35,7 +57,16 @@ impl StdlibModule for TimeModule {
     fn has_function(&self, function: &str) -> bool {
         matches!(
             function,
-            "now" | "format" | "diff" | "day_of_week" | "start_of_day"
+            "now"
+                | "format"
+                | "diff"
+                | "day_of_week"
+                | "start_of_day"
+                | "relative"
+                | "duration_format"
+                | "between"
+                | "quarter"
+                | "week_of_year"
         )
     }
 
@@ -46,21 +77,38 @@ impl StdlibModule for TimeModule {
             "diff" => self.diff(args),
             "day_of_week" => self.day_of_week(args),
             "start_of_day" => self.start_of_day(args),
+            "relative" => self.relative(args),
+            "duration_format" => self.duration_format(args),
+            "between" => self.between(args),
+            "quarter" => self.quarter(args),
+            "week_of_year" => self.week_of_year(args),
             _ => Err(StdlibError::unknown_function("time", function)),
         }
     }
+
+    fn arity(&self, function: &str) -> Option<Arity> {
+        Some(match function {
+            "now" => Arity::Exact(0),
+            "format" | "diff" | "relative" => Arity::Exact(2),
+            "day_of_week" | "start_of_day" | "duration_format" | "quarter" | "week_of_year" => {
+                Arity::Exact(1)
+            }
+            "between" => Arity::Exact(3),
+            _ => return None,
+        })
+    }
 }
 
 impl TimeModule {
     /// time.now() → number
-    /// Returns a deterministic stub value (0). In production, the host injects
-    /// the current timestamp via `env.host_call`.
+    /// Returns the instance's fixed timestamp: 0 unless injected with
+    /// `TimeModule::with_now`. In production, the host injects the current
+    /// timestamp via `env.host_call`.
     fn now(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
         if !args.is_empty() {
             return Err(StdlibError::wrong_args("time.now", 0, args.len()));
         }
-        // Deterministic stub — host provides real value at runtime
-        Ok(Value::Number(0.0))
+        Ok(Value::Number(self.now))
     }
 
     /// time.format(timestamp, pattern) → string
@@ -122,6 +170,122 @@ impl TimeModule {
         let day_start = (ts / MS_PER_DAY).floor() * MS_PER_DAY;
         Ok(Value::Number(day_start))
     }
+
+    /// time.relative(from, to) → string
+    /// Describes `from` relative to `to` ("5 minutes ago", "in 2 hours").
+    /// Thresholds on the absolute difference (units are floored):
+    /// - under 1 minute → "just now"
+    /// - under 1 hour → minutes
+    /// - under 1 day → hours
+    /// - otherwise → days
+    fn relative(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 2 {
+            return Err(StdlibError::wrong_args("time.relative", 2, args.len()));
+        }
+        let from = extract_number("time.relative", &args[0], 1)?;
+        let to = extract_number("time.relative", &args[1], 2)?;
+        let delta = to - from;
+        let abs = delta.abs();
+
+        let (count, unit) = if abs < MS_PER_MINUTE {
+            return Ok(Value::String("just now".to_string()));
+        } else if abs < MS_PER_HOUR {
+            ((abs / MS_PER_MINUTE).floor() as i64, "minute")
+        } else if abs < MS_PER_DAY {
+            ((abs / MS_PER_HOUR).floor() as i64, "hour")
+        } else {
+            ((abs / MS_PER_DAY).floor() as i64, "day")
+        };
+        let plural = if count == 1 { "" } else { "s" };
+
+        let phrase = if delta >= 0.0 {
+            format!("{count} {unit}{plural} ago")
+        } else {
+            format!("in {count} {unit}{plural}")
+        };
+        Ok(Value::String(phrase))
+    }
+
+    /// time.duration_format(millis) → string
+    /// Renders a non-negative duration as `H:MM:SS`, or `M:SS` under an hour.
+    /// Sub-second remainders are truncated. Traps on negative input.
+    fn duration_format(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 1 {
+            return Err(StdlibError::wrong_args(
+                "time.duration_format",
+                1,
+                args.len(),
+            ));
+        }
+        let millis = extract_number("time.duration_format", &args[0], 1)?;
+        if millis < 0.0 || !millis.is_finite() {
+            return Err(StdlibError::RuntimeError(
+                "time.duration_format: duration must be a non-negative finite number".to_string(),
+            ));
+        }
+
+        let total_sec = (millis / MS_PER_SECOND).floor() as u64;
+        let hours = total_sec / 3600;
+        let mins = (total_sec / 60) % 60;
+        let secs = total_sec % 60;
+
+        let result = if hours > 0 {
+            format!("{hours}:{mins:02}:{secs:02}")
+        } else {
+            format!("{mins}:{secs:02}")
+        };
+        Ok(Value::String(result))
+    }
+
+    /// time.between(timestamp, start, end) → bool
+    /// Half-open window check: `start <= timestamp < end`, so back-to-back
+    /// windows never both match a boundary instant. Traps if `start > end`.
+    fn between(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 3 {
+            return Err(StdlibError::wrong_args("time.between", 3, args.len()));
+        }
+        let ts = extract_number("time.between", &args[0], 1)?;
+        let start = extract_number("time.between", &args[1], 2)?;
+        let end = extract_number("time.between", &args[2], 3)?;
+        if start > end {
+            return Err(StdlibError::RuntimeError(format!(
+                "time.between: start ({start}) is after end ({end})"
+            )));
+        }
+        Ok(Value::Bool(start <= ts && ts < end))
+    }
+
+    /// time.quarter(timestamp) → number
+    /// Calendar quarter of the (UTC) date: 1 (Jan–Mar) through 4 (Oct–Dec).
+    fn quarter(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 1 {
+            return Err(StdlibError::wrong_args("time.quarter", 1, args.len()));
+        }
+        let ts = extract_number("time.quarter", &args[0], 1)?;
+        let (_, month, _, _, _, _) = timestamp_to_parts(ts);
+        Ok(Value::Number(((month - 1) / 3 + 1) as f64))
+    }
+
+    /// time.week_of_year(timestamp) → number
+    /// ISO-8601 week number (1–53) of the (UTC) date. ISO weeks start on
+    /// Monday, and week 1 is the week containing the year's first Thursday.
+    /// Dates in early January can therefore fall in week 52/53 of the
+    /// previous year, and late-December dates in week 1 of the next.
+    fn week_of_year(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 1 {
+            return Err(StdlibError::wrong_args("time.week_of_year", 1, args.len()));
+        }
+        let ts = extract_number("time.week_of_year", &args[0], 1)?;
+        let days = (ts as i64).div_euclid(MS_PER_DAY as i64);
+        // Monday = 0; epoch was a Thursday (3)
+        let weekday = (days + 3).rem_euclid(7);
+        // The Thursday of this ISO week decides which year the week belongs to
+        let thursday = days - weekday + 3;
+        let (iso_year, _, _) = days_to_civil(thursday);
+        let jan1 = days_from_civil(iso_year, 1, 1);
+        let week = (thursday - jan1) / 7 + 1;
+        Ok(Value::Number(week as f64))
+    }
 }
 
 // ── Date arithmetic helpers ─────────────────────────────────────────────────
@@ -158,6 +322,18 @@ fn days_to_civil(days: i64) -> (i64, u32, u32) {
     (y, m, d)
 }
 
+/// Convert (year, month, day) to days since Unix epoch.
+/// Inverse of [`days_to_civil`], from the same algorithm family.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = (y - era * 400) as u32; // year of era [0, 399]
+    let mp = if month > 2 { month - 3 } else { month + 9 }; // March-based month [0, 11]
+    let doy = (153 * mp + 2) / 5 + day - 1; // day of year [0, 365]
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy; // day of era [0, 146096]
+    era * 146097 + doe as i64 - 719468
+}
+
 // ── Helpers ──────────────────────────────────────────────────────────────────
 
 fn extract_number(func: &str, val: &Value, pos: usize) -> Result<f64, StdlibError> {
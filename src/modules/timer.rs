@@ -6,7 +6,7 @@
 //! returns the expected types.
 
 use crate::error::StdlibError;
-use crate::module::StdlibModule;
+use crate::module::{Arity, StdlibModule};
 use crate::value::Value;
 
 /// The `timer` stdlib module.
@@ -42,6 +42,15 @@ impl StdlibModule for TimerModule {
             _ => Err(StdlibError::unknown_function("timer", function)),
         }
     }
+
+    fn arity(&self, function: &str) -> Option<Arity> {
+        Some(match function {
+            "start" | "start_once" => Arity::Exact(2),
+            "stop" => Arity::Exact(1),
+            "stop_all" => Arity::Exact(0),
+            _ => return None,
+        })
+    }
 }
 
 impl TimerModule {
@@ -0,0 +1,179 @@
+//! `validate` stdlib module — common input checks.
+//!
+//! Functions: is_email, is_url, is_numeric, is_alpha, in_range, length_between.
+//! Every function returns a bool. The checks are deliberately pragmatic —
+//! good enough for form validation, not full RFC compliance.
+
+use crate::error::StdlibError;
+use crate::module::{Arity, StdlibModule};
+use crate::value::Value;
+
+/// The `validate` stdlib module.
+pub struct ValidateModule;
+
+impl ValidateModule {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for ValidateModule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StdlibModule for ValidateModule {
+    fn name(&self) -> &'static str {
+        "validate"
+    }
+
+    fn has_function(&self, function: &str) -> bool {
+        matches!(
+            function,
+            "is_email" | "is_url" | "is_numeric" | "is_alpha" | "in_range" | "length_between"
+        )
+    }
+
+    fn call(&self, function: &str, args: Vec<Value>) -> Result<Value, StdlibError> {
+        match function {
+            "is_email" => self.is_email(args),
+            "is_url" => self.is_url(args),
+            "is_numeric" => self.is_numeric(args),
+            "is_alpha" => self.is_alpha(args),
+            "in_range" => self.in_range(args),
+            "length_between" => self.length_between(args),
+            _ => Err(StdlibError::unknown_function("validate", function)),
+        }
+    }
+
+    fn arity(&self, function: &str) -> Option<Arity> {
+        Some(match function {
+            "is_email" | "is_url" | "is_numeric" | "is_alpha" => Arity::Exact(1),
+            "in_range" | "length_between" => Arity::Exact(3),
+            _ => return None,
+        })
+    }
+}
+
+impl ValidateModule {
+    /// validate.is_email(s) → bool
+    /// Exactly one `@`, a non-empty local part, and a domain of at least two
+    /// non-empty dot-separated labels. No whitespace anywhere.
+    fn is_email(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let s = expect_one_string("validate.is_email", &args)?;
+        let valid = match s.split_once('@') {
+            Some((local, domain)) => {
+                !local.is_empty()
+                    && !domain.contains('@')
+                    && !s.chars().any(char::is_whitespace)
+                    && domain.contains('.')
+                    && domain.split('.').all(|label| !label.is_empty())
+            }
+            None => false,
+        };
+        Ok(Value::Bool(valid))
+    }
+
+    /// validate.is_url(s) → bool
+    /// An `http://` or `https://` scheme (case-insensitive) followed by a
+    /// non-empty host. No whitespace anywhere.
+    fn is_url(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let s = expect_one_string("validate.is_url", &args)?;
+        let rest = match s.split_once("://") {
+            Some((scheme, rest))
+                if scheme.eq_ignore_ascii_case("http") || scheme.eq_ignore_ascii_case("https") =>
+            {
+                rest
+            }
+            _ => return Ok(Value::Bool(false)),
+        };
+        let host = rest.split(['/', '?', '#']).next().unwrap_or("");
+        Ok(Value::Bool(
+            !host.is_empty() && !s.chars().any(char::is_whitespace),
+        ))
+    }
+
+    /// validate.is_numeric(s) → bool
+    /// One or more ASCII digits and nothing else (no sign, point or spaces),
+    /// e.g. for PINs and postal codes. Use `convert.to_number_strict` to
+    /// validate decimal numbers.
+    fn is_numeric(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let s = expect_one_string("validate.is_numeric", &args)?;
+        Ok(Value::Bool(
+            !s.is_empty() && s.chars().all(|c| c.is_ascii_digit()),
+        ))
+    }
+
+    /// validate.is_alpha(s) → bool
+    /// One or more alphabetic characters (Unicode letters) and nothing else.
+    fn is_alpha(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        let s = expect_one_string("validate.is_alpha", &args)?;
+        Ok(Value::Bool(
+            !s.is_empty() && s.chars().all(char::is_alphabetic),
+        ))
+    }
+
+    /// validate.in_range(n, min, max) → bool
+    /// Inclusive on both ends. NaN is never in range.
+    fn in_range(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 3 {
+            return Err(StdlibError::wrong_args("validate.in_range", 3, args.len()));
+        }
+        let n = extract_number("validate.in_range", &args[0], 1)?;
+        let min = extract_number("validate.in_range", &args[1], 2)?;
+        let max = extract_number("validate.in_range", &args[2], 3)?;
+        Ok(Value::Bool(min <= n && n <= max))
+    }
+
+    /// validate.length_between(s, min, max) → bool
+    /// Character (not byte) length within `min..=max`, matching
+    /// `string.length`.
+    fn length_between(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
+        if args.len() != 3 {
+            return Err(StdlibError::wrong_args(
+                "validate.length_between",
+                3,
+                args.len(),
+            ));
+        }
+        let s = extract_string("validate.length_between", &args[0], 1)?;
+        let min = extract_number("validate.length_between", &args[1], 2)?;
+        let max = extract_number("validate.length_between", &args[2], 3)?;
+        let len = s.chars().count() as f64;
+        Ok(Value::Bool(min <= len && len <= max))
+    }
+}
+
+// ── Helpers ──────────────────────────────────────────────────────────────────
+
+fn expect_one_string<'a>(func: &str, args: &'a [Value]) -> Result<&'a str, StdlibError> {
+    if args.len() != 1 {
+        return Err(StdlibError::wrong_args(func, 1, args.len()));
+    }
+    extract_string(func, &args[0], 1)
+}
+
+fn extract_number(func: &str, val: &Value, pos: usize) -> Result<f64, StdlibError> {
+    match val {
+        Value::Number(n) => Ok(*n),
+        _ => Err(StdlibError::type_mismatch(
+            func,
+            pos,
+            "number",
+            val.type_name(),
+        )),
+    }
+}
+
+fn extract_string<'a>(func: &str, val: &'a Value, pos: usize) -> Result<&'a str, StdlibError> {
+    match val {
+        Value::String(s) => Ok(s),
+        _ => Err(StdlibError::type_mismatch(
+            func,
+            pos,
+            "string",
+            val.type_name(),
+        )),
+    }
+}
@@ -0,0 +1,125 @@
+//! Name-keyed collection of stdlib modules.
+//!
+//! Hosts that load untrusted PEPL code can use [`StdlibRegistry::validate_call`]
+//! to reject calls with the wrong number of arguments up front, using each
+//! module's declared [`Arity`](crate::module::Arity), before anything runs.
+
+use std::collections::BTreeMap;
+
+use crate::error::StdlibError;
+use crate::module::{Arity, StdlibModule};
+use crate::modules::{
+    convert::ConvertModule, core::CoreModule, csv::CsvModule, encoding::EncodingModule,
+    http::HttpModule, json::JsonModule, list::ListModule, location::LocationModule,
+    math::MathModule, notifications::NotificationsModule, path::PathModule, record::RecordModule,
+    storage::StorageModule, string::StringModule, time::TimeModule, timer::TimerModule,
+    validate::ValidateModule,
+};
+use crate::value::Value;
+
+/// Stdlib modules indexed by [`StdlibModule::name`].
+pub struct StdlibRegistry {
+    modules: BTreeMap<&'static str, Box<dyn StdlibModule>>,
+}
+
+impl StdlibRegistry {
+    /// Registry holding every built-in module (pure and capability) in its
+    /// default configuration.
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+        registry.register(CoreModule::new());
+        registry.register(MathModule::new());
+        registry.register(StringModule::new());
+        registry.register(ListModule::new());
+        registry.register(RecordModule::new());
+        registry.register(TimeModule::new());
+        registry.register(ConvertModule::new());
+        registry.register(JsonModule::new());
+        registry.register(TimerModule::new());
+        registry.register(EncodingModule::new());
+        registry.register(ValidateModule::new());
+        registry.register(PathModule::new());
+        registry.register(CsvModule::new());
+        registry.register(HttpModule::new());
+        registry.register(StorageModule::new());
+        registry.register(LocationModule::new());
+        registry.register(NotificationsModule::new());
+        registry
+    }
+
+    /// Registry with no modules.
+    pub fn empty() -> Self {
+        Self {
+            modules: BTreeMap::new(),
+        }
+    }
+
+    /// Add `module`, replacing any module already registered under the same
+    /// name (e.g. a `CoreModule` built with capabilities).
+    pub fn register(&mut self, module: impl StdlibModule + 'static) {
+        self.modules.insert(module.name(), Box::new(module));
+    }
+
+    /// The module registered under `name`, if any.
+    pub fn get(&self, name: &str) -> Option<&dyn StdlibModule> {
+        self.modules.get(name).map(|module| module.as_ref())
+    }
+
+    /// Call `module.function(args...)`.
+    pub fn call(
+        &self,
+        module: &str,
+        function: &str,
+        args: Vec<Value>,
+    ) -> Result<Value, StdlibError> {
+        match self.get(module) {
+            Some(m) => m.call(function, args),
+            None => Err(StdlibError::unknown_function(module, function)),
+        }
+    }
+
+    /// Check that `module.function` exists and accepts `arg_count` arguments,
+    /// without executing it.
+    ///
+    /// Returns `UnknownFunction` for an unknown module or function and
+    /// `WrongArgCount` when the count doesn't match the declared arity. For a
+    /// range, `expected` is the nearest bound. Functions whose module declares
+    /// no arity pass on existence alone. Argument types are not checked.
+    pub fn validate_call(
+        &self,
+        module: &str,
+        function: &str,
+        arg_count: usize,
+    ) -> Result<(), StdlibError> {
+        let m = match self.get(module) {
+            Some(m) if m.has_function(function) => m,
+            _ => return Err(StdlibError::unknown_function(module, function)),
+        };
+        match m.arity(function) {
+            Some(arity) if !arity.accepts(arg_count) => {
+                let expected = match arity {
+                    Arity::Exact(n) | Arity::AtLeast(n) => n,
+                    Arity::Range(min, max) => {
+                        if arg_count < min {
+                            min
+                        } else {
+                            max
+                        }
+                    }
+                };
+                Err(StdlibError::wrong_args(
+                    &format!("{module}.{function}"),
+                    expected,
+                    arg_count,
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Default for StdlibRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,3 +1,4 @@
+use std::cmp::Ordering;
 use std::collections::BTreeMap;
 use std::fmt;
 use std::sync::Arc;
@@ -68,24 +69,55 @@ pub enum Value {
     Function(StdlibFn),
 }
 
+/// Closure type behind a [`StdlibFn`].
+type NativeFn = dyn Fn(Vec<Value>) -> Result<Value, StdlibError> + Send + Sync;
+
 /// A callable function value for higher-order stdlib operations.
 ///
 /// Wraps an `Arc<dyn Fn>` so it can be cloned, and provides Debug/PartialEq
 /// implementations that the derive macros can't auto-generate for `dyn Fn`.
+///
+/// **Breaking change:** the fields are private. Build values with
+/// [`StdlibFn::new`] (or `From<Arc<..>>` for an existing closure `Arc`)
+/// instead of the former `StdlibFn(arc)` tuple constructor, and use
+/// [`StdlibFn::call`] instead of `.0`.
 #[derive(Clone)]
-pub struct StdlibFn(pub Arc<dyn Fn(Vec<Value>) -> Result<Value, StdlibError> + Send + Sync>);
+pub struct StdlibFn {
+    f: Arc<NativeFn>,
+    /// Declared arity, if the creator knows it.
+    arity: Option<usize>,
+}
 
 impl StdlibFn {
     /// Create a new stdlib function from a closure.
     pub fn new(
         f: impl Fn(Vec<Value>) -> Result<Value, StdlibError> + Send + Sync + 'static,
     ) -> Self {
-        Self(Arc::new(f))
+        Self::from(Arc::new(f) as Arc<NativeFn>)
+    }
+
+    /// Declare how many arguments this function expects. Higher-order stdlib
+    /// functions use it to report a clear error instead of calling with the
+    /// wrong number of arguments.
+    pub fn with_arity(mut self, arity: usize) -> Self {
+        self.arity = Some(arity);
+        self
+    }
+
+    /// The declared arity, or `None` if unknown.
+    pub fn arity(&self) -> Option<usize> {
+        self.arity
     }
 
     /// Call the function with the given arguments.
     pub fn call(&self, args: Vec<Value>) -> Result<Value, StdlibError> {
-        (self.0)(args)
+        (self.f)(args)
+    }
+}
+
+impl From<Arc<NativeFn>> for StdlibFn {
+    fn from(f: Arc<NativeFn>) -> Self {
+        Self { f, arity: None }
     }
 }
 
@@ -98,7 +130,7 @@ impl fmt::Debug for StdlibFn {
 impl PartialEq for StdlibFn {
     fn eq(&self, other: &Self) -> bool {
         // Function identity by Arc pointer equality
-        Arc::ptr_eq(&self.0, &other.0)
+        Arc::ptr_eq(&self.f, &other.f)
     }
 }
 
@@ -121,6 +153,7 @@ pub enum ResultValue {
 //   - color:   RGBA value comparison
 //   - result:  same variant + same inner value
 //   - record:  structural (type_name ignored — type checker ensures compatibility)
+//              (`Value::nominal_eq` opts into comparing type_name too)
 //   - sum:     nominal (type_name + variant + fields must all match)
 //   - Note: Functions/lambdas live in EvalValue (pepl-eval), not here
 
@@ -179,6 +212,218 @@ impl PartialEq for ResultValue {
     }
 }
 
+impl Value {
+    /// Structural equality that treats numbers within `epsilon` as equal,
+    /// recursing through lists, records, colors, results and sum variants.
+    /// Non-numeric leaves fall back to exact equality. Intended for test code
+    /// comparing float-heavy values.
+    pub fn approx_eq(&self, other: &Value, epsilon: f64) -> bool {
+        let close = |a: f64, b: f64| a == b || (a - b).abs() <= epsilon;
+        match (self, other) {
+            (Value::Number(a), Value::Number(b)) => close(*a, *b),
+            (Value::List(a), Value::List(b)) => approx_eq_seq(a, b, epsilon),
+            (Value::Record { fields: a, .. }, Value::Record { fields: b, .. }) => {
+                a.len() == b.len()
+                    && a.iter()
+                        .zip(b.iter())
+                        .all(|((ka, va), (kb, vb))| ka == kb && va.approx_eq(vb, epsilon))
+            }
+            (
+                Value::Color {
+                    r: r1,
+                    g: g1,
+                    b: b1,
+                    a: a1,
+                },
+                Value::Color {
+                    r: r2,
+                    g: g2,
+                    b: b2,
+                    a: a2,
+                },
+            ) => close(*r1, *r2) && close(*g1, *g2) && close(*b1, *b2) && close(*a1, *a2),
+            (Value::Result(a), Value::Result(b)) => match (a.as_ref(), b.as_ref()) {
+                (ResultValue::Ok(x), ResultValue::Ok(y)) => x.approx_eq(y, epsilon),
+                (ResultValue::Err(x), ResultValue::Err(y)) => x.approx_eq(y, epsilon),
+                _ => false,
+            },
+            (
+                Value::SumVariant {
+                    type_name: t1,
+                    variant: v1,
+                    fields: f1,
+                },
+                Value::SumVariant {
+                    type_name: t2,
+                    variant: v2,
+                    fields: f2,
+                },
+            ) => t1 == t2 && v1 == v2 && approx_eq_seq(f1, f2, epsilon),
+            _ => self == other,
+        }
+    }
+
+    /// Like `==`, but records must also have the same `type_name` — at every
+    /// nesting level — so `Foo { x: 1 }` and `Bar { x: 1 }` (or an anonymous
+    /// `{ x: 1 }`) are not equal. For hosts that need nominal record
+    /// identity; the default `==` stays structural.
+    pub fn nominal_eq(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::List(a), Value::List(b)) => nominal_eq_seq(a, b),
+            (
+                Value::Record {
+                    type_name: t1,
+                    fields: a,
+                },
+                Value::Record {
+                    type_name: t2,
+                    fields: b,
+                },
+            ) => {
+                t1 == t2
+                    && a.len() == b.len()
+                    && a.iter()
+                        .zip(b.iter())
+                        .all(|((ka, va), (kb, vb))| ka == kb && va.nominal_eq(vb))
+            }
+            (Value::Result(a), Value::Result(b)) => match (a.as_ref(), b.as_ref()) {
+                (ResultValue::Ok(x), ResultValue::Ok(y)) => x.nominal_eq(y),
+                (ResultValue::Err(x), ResultValue::Err(y)) => x.nominal_eq(y),
+                _ => false,
+            },
+            (
+                Value::SumVariant {
+                    type_name: t1,
+                    variant: v1,
+                    fields: f1,
+                },
+                Value::SumVariant {
+                    type_name: t2,
+                    variant: v2,
+                    fields: f2,
+                },
+            ) => t1 == t2 && v1 == v2 && nominal_eq_seq(f1, f2),
+            _ => self == other,
+        }
+    }
+}
+
+fn approx_eq_seq(a: &[Value], b: &[Value], epsilon: f64) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(x, y)| x.approx_eq(y, epsilon))
+}
+
+fn nominal_eq_seq(a: &[Value], b: &[Value]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(x, y)| x.nominal_eq(y))
+}
+
+// ── Ordering ──────────────────────────────────────────────────────────────────
+//
+// Deterministic total order across all values (used by natural-order sorting):
+//   nil < bool < number < string < list < record < color < result < sum < function
+// Within a kind:
+//   - bool:     false < true
+//   - number:   f64::total_cmp (so -0.0 < 0.0 and NaN sorts last)
+//   - string:   lexicographic by bytes
+//   - list:     lexicographic element-by-element, shorter prefix first
+//   - record:   lexicographic over (key, value) pairs in BTreeMap order;
+//               type_name ignored, matching structural equality
+//   - color:    r, then g, b, a
+//   - result:   Ok < Err, then inner value
+//   - sum:      type_name, then variant, then fields
+//   - function: all functions compare equal (no meaningful order)
+
+impl Value {
+    /// Compare two values under PEPL's deterministic cross-type total order.
+    pub fn total_cmp(&self, other: &Value) -> Ordering {
+        let rank = self.kind_rank().cmp(&other.kind_rank());
+        if rank != Ordering::Equal {
+            return rank;
+        }
+        match (self, other) {
+            (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+            (Value::Number(a), Value::Number(b)) => a.total_cmp(b),
+            (Value::String(a), Value::String(b)) => a.cmp(b),
+            (Value::List(a), Value::List(b)) => cmp_seq(a, b),
+            (Value::Record { fields: a, .. }, Value::Record { fields: b, .. }) => {
+                for ((ka, va), (kb, vb)) in a.iter().zip(b.iter()) {
+                    let ord = ka.cmp(kb).then_with(|| va.total_cmp(vb));
+                    if ord != Ordering::Equal {
+                        return ord;
+                    }
+                }
+                a.len().cmp(&b.len())
+            }
+            (
+                Value::Color {
+                    r: r1,
+                    g: g1,
+                    b: b1,
+                    a: a1,
+                },
+                Value::Color {
+                    r: r2,
+                    g: g2,
+                    b: b2,
+                    a: a2,
+                },
+            ) => r1
+                .total_cmp(r2)
+                .then_with(|| g1.total_cmp(g2))
+                .then_with(|| b1.total_cmp(b2))
+                .then_with(|| a1.total_cmp(a2)),
+            (Value::Result(a), Value::Result(b)) => match (a.as_ref(), b.as_ref()) {
+                (ResultValue::Ok(x), ResultValue::Ok(y)) => x.total_cmp(y),
+                (ResultValue::Err(x), ResultValue::Err(y)) => x.total_cmp(y),
+                (ResultValue::Ok(_), ResultValue::Err(_)) => Ordering::Less,
+                (ResultValue::Err(_), ResultValue::Ok(_)) => Ordering::Greater,
+            },
+            (
+                Value::SumVariant {
+                    type_name: t1,
+                    variant: v1,
+                    fields: f1,
+                },
+                Value::SumVariant {
+                    type_name: t2,
+                    variant: v2,
+                    fields: f2,
+                },
+            ) => t1
+                .cmp(t2)
+                .then_with(|| v1.cmp(v2))
+                .then_with(|| cmp_seq(f1, f2)),
+            _ => Ordering::Equal, // Nil/Nil, Function/Function
+        }
+    }
+
+    /// Position of this value's kind in the cross-type order.
+    fn kind_rank(&self) -> u8 {
+        match self {
+            Value::Nil => 0,
+            Value::Bool(_) => 1,
+            Value::Number(_) => 2,
+            Value::String(_) => 3,
+            Value::List(_) => 4,
+            Value::Record { .. } => 5,
+            Value::Color { .. } => 6,
+            Value::Result(_) => 7,
+            Value::SumVariant { .. } => 8,
+            Value::Function(_) => 9,
+        }
+    }
+}
+
+/// Lexicographic comparison of two value sequences under `total_cmp`.
+fn cmp_seq(a: &[Value], b: &[Value]) -> Ordering {
+    for (x, y) in a.iter().zip(b.iter()) {
+        let ord = x.total_cmp(y);
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+    a.len().cmp(&b.len())
+}
+
 // ── Display ───────────────────────────────────────────────────────────────────
 //
 // Used by `core.log`, `convert.to_string`, and `string.from`.
@@ -255,6 +500,161 @@ impl fmt::Display for Value {
     }
 }
 
+// ── Canonical encoding ────────────────────────────────────────────────────────
+//
+// Deterministic, unambiguous text form for hashing and cross-process equality.
+// Every value carries a type tag, so `"1"` and `1` never collide:
+//   nil → `n`    bool → `b:true`    number → `d:1.5`    string → `s:"a\"b"`
+//   list → `l[…,…]`    record → `r{"k":…,…}` (BTreeMap order, type_name ignored)
+//   color → `c(r,g,b,a)`    result → `ok(…)` / `err(…)`
+//   sum → `v:"Type"."Variant"(…)`    function → `f`
+// Values that compare equal encode identically (-0.0 is written as 0.0).
+
+impl Value {
+    /// Deterministic, type-tagged encoding suitable for hashing or equality
+    /// checks across process boundaries. Unlike `Display`, distinguishes the
+    /// string `"1"` from the number `1`.
+    pub fn canonical_string(&self) -> String {
+        let mut out = String::new();
+        self.write_canonical(&mut out);
+        out
+    }
+
+    fn write_canonical(&self, out: &mut String) {
+        match self {
+            Value::Nil => out.push('n'),
+            Value::Bool(b) => {
+                out.push_str(if *b { "b:true" } else { "b:false" });
+            }
+            Value::Number(n) => {
+                out.push_str("d:");
+                write_canonical_number(*n, out);
+            }
+            Value::String(s) => {
+                out.push_str("s:");
+                write_canonical_str(s, out);
+            }
+            Value::List(items) => {
+                out.push_str("l[");
+                write_canonical_seq(items, out);
+                out.push(']');
+            }
+            Value::Record { fields, .. } => {
+                out.push_str("r{");
+                for (i, (key, val)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    write_canonical_str(key, out);
+                    out.push(':');
+                    val.write_canonical(out);
+                }
+                out.push('}');
+            }
+            Value::Color { r, g, b, a } => {
+                out.push_str("c(");
+                for (i, channel) in [r, g, b, a].into_iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    write_canonical_number(*channel, out);
+                }
+                out.push(')');
+            }
+            Value::Result(res) => {
+                let (tag, inner) = match res.as_ref() {
+                    ResultValue::Ok(v) => ("ok(", v),
+                    ResultValue::Err(v) => ("err(", v),
+                };
+                out.push_str(tag);
+                inner.write_canonical(out);
+                out.push(')');
+            }
+            Value::SumVariant {
+                type_name,
+                variant,
+                fields,
+            } => {
+                out.push_str("v:");
+                write_canonical_str(type_name, out);
+                out.push('.');
+                write_canonical_str(variant, out);
+                out.push('(');
+                write_canonical_seq(fields, out);
+                out.push(')');
+            }
+            Value::Function(_) => out.push('f'),
+        }
+    }
+}
+
+/// Shortest round-trip form of a number; `-0.0` folds into `0.0` because
+/// the two compare equal.
+fn write_canonical_number(n: f64, out: &mut String) {
+    let n = if n == 0.0 { 0.0 } else { n };
+    out.push_str(&format!("{n:?}"));
+}
+
+/// Quoted string with `"` and `\` escaped — enough to make the closing
+/// quote unambiguous.
+fn write_canonical_str(s: &str, out: &mut String) {
+    out.push('"');
+    for c in s.chars() {
+        if c == '"' || c == '\\' {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out.push('"');
+}
+
+fn write_canonical_seq(items: &[Value], out: &mut String) {
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        item.write_canonical(out);
+    }
+}
+
+// ── Size estimate ─────────────────────────────────────────────────────────────
+
+impl Value {
+    /// Approximate memory footprint in bytes, for enforcing memory budgets.
+    ///
+    /// This is an estimate, not an exact allocation count: every value costs
+    /// `size_of::<Value>()`, plus string bytes (including record keys and
+    /// type/variant names) and the recursive size of nested values.
+    /// Allocator overhead and spare `Vec`/`String` capacity are ignored, and
+    /// functions count as their handle only.
+    pub fn size_hint(&self) -> usize {
+        let heap = match self {
+            Value::Number(_) | Value::Bool(_) | Value::Nil | Value::Color { .. } => 0,
+            Value::Function(_) => 0,
+            Value::String(s) => s.len(),
+            Value::List(items) => items.iter().map(Value::size_hint).sum(),
+            Value::Record { type_name, fields } => {
+                type_name.as_ref().map_or(0, String::len)
+                    + fields
+                        .iter()
+                        .map(|(key, val)| key.len() + val.size_hint())
+                        .sum::<usize>()
+            }
+            Value::Result(res) => match res.as_ref() {
+                ResultValue::Ok(v) | ResultValue::Err(v) => v.size_hint(),
+            },
+            Value::SumVariant {
+                type_name,
+                variant,
+                fields,
+            } => {
+                type_name.len() + variant.len() + fields.iter().map(Value::size_hint).sum::<usize>()
+            }
+        };
+        std::mem::size_of::<Value>() + heap
+    }
+}
+
 // ── Constructors & Helpers ────────────────────────────────────────────────────
 
 impl Value {
@@ -321,6 +721,28 @@ impl Value {
         }
     }
 
+    /// Create an anonymous record from `(key, value)` pairs.
+    ///
+    /// Later duplicates overwrite earlier ones, matching `BTreeMap::insert`.
+    ///
+    /// ```
+    /// use pepl_stdlib::Value;
+    /// let point = Value::record_of(&[("x", 1.into()), ("y", 2.into())]);
+    /// assert_eq!(point.as_record().unwrap().len(), 2);
+    /// ```
+    pub fn record_of(pairs: &[(&str, Value)]) -> Value {
+        let fields = pairs
+            .iter()
+            .map(|(key, val)| (key.to_string(), val.clone()))
+            .collect();
+        Value::record(fields)
+    }
+
+    /// Create a list from a slice of values.
+    pub fn list_of(items: &[Value]) -> Value {
+        Value::List(items.to_vec())
+    }
+
     /// Create a unit sum variant (no payload fields).
     pub fn unit_variant(type_name: impl Into<String>, variant: impl Into<String>) -> Value {
         Value::SumVariant {
@@ -383,6 +805,22 @@ impl Value {
         }
     }
 
+    /// Try to extract color channels: `(r, g, b, a)`.
+    pub fn as_color(&self) -> Option<(f64, f64, f64, f64)> {
+        match self {
+            Value::Color { r, g, b, a } => Some((*r, *g, *b, *a)),
+            _ => None,
+        }
+    }
+
+    /// Try to extract a result reference, returning `None` if not a `Result`.
+    pub fn as_result(&self) -> Option<&ResultValue> {
+        match self {
+            Value::Result(r) => Some(r),
+            _ => None,
+        }
+    }
+
     /// Try to extract sum variant info: `(type_name, variant, fields)`.
     pub fn as_variant(&self) -> Option<(&str, &str, &[Value])> {
         match self {
@@ -403,6 +841,11 @@ impl Value {
         }
     }
 
+    /// Returns the declared arity if this is a function with a known arity.
+    pub fn function_arity(&self) -> Option<usize> {
+        self.as_function().and_then(StdlibFn::arity)
+    }
+
     /// Returns the declared type name for named records and sum variants.
     /// Returns `None` for anonymous records and all other value types.
     pub fn declared_type_name(&self) -> Option<&str> {
@@ -417,6 +860,53 @@ impl Value {
     }
 }
 
+// ── Builders ──────────────────────────────────────────────────────────────────
+
+/// Incremental builder for record values.
+///
+/// ```
+/// use pepl_stdlib::{RecordBuilder, Value};
+/// let todo = RecordBuilder::named("Todo")
+///     .field("title", "Buy milk")
+///     .field("done", false)
+///     .build();
+/// assert_eq!(todo.type_name(), "Todo");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RecordBuilder {
+    type_name: Option<String>,
+    fields: BTreeMap<String, Value>,
+}
+
+impl RecordBuilder {
+    /// Start an anonymous record.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start a named record (e.g., `type Todo = { ... }`).
+    pub fn named(type_name: impl Into<String>) -> Self {
+        Self {
+            type_name: Some(type_name.into()),
+            fields: BTreeMap::new(),
+        }
+    }
+
+    /// Set a field, overwriting any previous value for the same key.
+    pub fn field(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.fields.insert(key.into(), value.into());
+        self
+    }
+
+    /// Finish building and return the record value.
+    pub fn build(self) -> Value {
+        Value::Record {
+            type_name: self.type_name,
+            fields: self.fields,
+        }
+    }
+}
+
 // ── From impls ────────────────────────────────────────────────────────────────
 
 impl From<f64> for Value {
@@ -457,3 +947,39 @@ impl From<BTreeMap<String, Value>> for Value {
         }
     }
 }
+
+// ── Thread safety ─────────────────────────────────────────────────────────────
+//
+// `Value` is `Send + Sync` by construction: every container is owned, and
+// `StdlibFn` requires `Send + Sync` closures. The `threadsafe` feature turns
+// that into a compile-time guarantee and adds `SharedValue` for hosts that hand
+// the same value to several worker threads.
+//
+// Trade-offs: `Value::List` stays a plain `Vec` in both modes. Swapping in
+// `Arc` storage only under the feature would change a public payload type and
+// break `Value::List(vec![..])` code anywhere in the dependency graph, so
+// features must stay additive. List operations borrow their input instead, so
+// read-only ones don't copy the list and ones that return a modified list copy
+// it once. Wrapping a large value in `SharedValue` makes cross-thread handoff
+// an `Arc` bump, but each stdlib call still receives an owned copy of its
+// arguments.
+
+/// Reference-counted, immutable value that is cheap to clone across threads.
+#[cfg(feature = "threadsafe")]
+pub type SharedValue = Arc<Value>;
+
+#[cfg(feature = "threadsafe")]
+impl Value {
+    /// Wrap this value for cheap sharing between threads.
+    pub fn into_shared(self) -> SharedValue {
+        Arc::new(self)
+    }
+}
+
+#[cfg(feature = "threadsafe")]
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Value>();
+    assert_send_sync::<StdlibFn>();
+    assert_send_sync::<StdlibError>();
+};
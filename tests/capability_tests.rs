@@ -8,9 +8,9 @@
 //! - 100-iteration determinism
 
 use pepl_stdlib::capability::{
-    self, CAP_HTTP, CAP_LOCATION, CAP_NOTIFICATIONS, CAP_STORAGE, HTTP_DELETE, HTTP_GET,
-    HTTP_PATCH, HTTP_POST, HTTP_PUT, LOCATION_CURRENT, NOTIFICATIONS_SEND, STORAGE_DELETE,
-    STORAGE_GET, STORAGE_KEYS, STORAGE_SET,
+    self, CapabilityRequest, CAP_HTTP, CAP_LOCATION, CAP_NOTIFICATIONS, CAP_STORAGE, HTTP_DELETE,
+    HTTP_GET, HTTP_PATCH, HTTP_POST, HTTP_PUT, LOCATION_CURRENT, NOTIFICATIONS_SEND,
+    STORAGE_DELETE, STORAGE_GET, STORAGE_GET_ALL, STORAGE_KEYS, STORAGE_SET,
 };
 use pepl_stdlib::modules::http::HttpModule;
 use pepl_stdlib::modules::location::LocationModule;
@@ -197,14 +197,70 @@ fn http_post_wrong_arg_type() {
         )
         .unwrap_err();
     assert!(matches!(err, StdlibError::TypeMismatch { .. }));
-    // second arg not string
+    // body neither a string nor JSON-serializable
+    let err = m
+        .call("post", vec![Value::String("url".into()), Value::Nil])
+        .unwrap_err();
+    assert!(matches!(err, StdlibError::TypeMismatch { .. }));
+}
+
+#[test]
+fn http_post_record_body_serializes_to_json() {
+    let m = HttpModule::new();
+    let body = Value::record_of(&[
+        ("name", Value::String("Ada".into())),
+        (
+            "tags",
+            Value::List(vec![Value::Number(1.0), Value::Bool(true)]),
+        ),
+    ]);
     let err = m
         .call(
             "post",
-            vec![Value::String("url".into()), Value::Number(1.0)],
+            vec![Value::String("https://api.example.com".into()), body],
         )
         .unwrap_err();
-    assert!(matches!(err, StdlibError::TypeMismatch { .. }));
+    let request = err.as_capability_request().unwrap();
+    assert_eq!(request.fn_id, HTTP_POST);
+    assert_eq!(
+        request.args[1],
+        Value::String(r#"{"name":"Ada","tags":[1.0,true]}"#.into())
+    );
+}
+
+#[test]
+fn http_body_kinds_serialize_for_put_and_patch() {
+    let m = HttpModule::new();
+    let url = || Value::String("https://api.example.com".into());
+    let body_of = |function: &str, body: Value| {
+        let err = m.call(function, vec![url(), body]).unwrap_err();
+        err.as_capability_request().unwrap().args[1].clone()
+    };
+    assert_eq!(
+        body_of("put", Value::List(vec![])),
+        Value::String("[]".into())
+    );
+    assert_eq!(
+        body_of("patch", Value::Bool(false)),
+        Value::String("false".into())
+    );
+    assert_eq!(
+        body_of("patch", Value::Number(2.5)),
+        Value::String("2.5".into())
+    );
+}
+
+#[test]
+fn http_string_body_is_unchanged() {
+    let m = HttpModule::new();
+    let raw = Value::String("{\"already\": \"json\"}".into());
+    let err = m
+        .call(
+            "post",
+            vec![Value::String("https://api.example.com".into()), raw.clone()],
+        )
+        .unwrap_err();
+    assert_eq!(err.as_capability_request().unwrap().args[1], raw);
 }
 
 #[test]
@@ -286,6 +342,7 @@ fn storage_has_function() {
     assert!(m.has_function("set"));
     assert!(m.has_function("delete"));
     assert!(m.has_function("keys"));
+    assert!(m.has_function("get_all"));
     assert!(!m.has_function("clear"));
     assert!(!m.has_function("remove"));
 }
@@ -383,6 +440,34 @@ fn storage_keys_wrong_arg_count() {
     assert!(matches!(err, StdlibError::WrongArgCount { .. }));
 }
 
+#[test]
+fn storage_get_all_returns_capability_call() {
+    let m = StorageModule::new();
+    assert_capability_call(&m, "get_all", vec![], CAP_STORAGE, STORAGE_GET_ALL);
+}
+
+#[test]
+fn storage_get_all_wrong_arg_count() {
+    let m = StorageModule::new();
+    let err = m
+        .call("get_all", vec![Value::String("x".into())])
+        .unwrap_err();
+    assert!(matches!(err, StdlibError::WrongArgCount { .. }));
+}
+
+#[test]
+fn storage_get_all_deterministic_100_iterations() {
+    let m = StorageModule::new();
+    let reference = format!("{}", m.call("get_all", vec![]).unwrap_err());
+    for i in 0..100 {
+        assert_eq!(
+            format!("{}", m.call("get_all", vec![]).unwrap_err()),
+            reference,
+            "storage.get_all not deterministic at iteration {i}"
+        );
+    }
+}
+
 #[test]
 fn storage_unknown_function() {
     let m = StorageModule::new();
@@ -538,6 +623,7 @@ fn resolve_ids_storage() {
     assert_eq!(capability::resolve_ids("storage", "set"), Some((2, 2)));
     assert_eq!(capability::resolve_ids("storage", "delete"), Some((2, 3)));
     assert_eq!(capability::resolve_ids("storage", "keys"), Some((2, 4)));
+    assert_eq!(capability::resolve_ids("storage", "get_all"), Some((2, 7)));
 }
 
 #[test]
@@ -667,6 +753,7 @@ fn all_capability_functions_return_capability_call_error() {
         (&storage, "set", vec![s(), s()]),
         (&storage, "delete", vec![s()]),
         (&storage, "keys", vec![]),
+        (&storage, "get_all", vec![]),
         (&location, "current", vec![]),
         (&notifications, "send", vec![s(), s()]),
     ];
@@ -681,3 +768,43 @@ fn all_capability_functions_return_capability_call_error() {
         );
     }
 }
+
+#[test]
+fn capability_call_converts_to_request() {
+    let url = Value::String("https://example.com".into());
+    let err = HttpModule::new()
+        .call("get", vec![url.clone()])
+        .unwrap_err();
+    assert_eq!(
+        err.as_capability_request(),
+        Some(CapabilityRequest {
+            module: "http".into(),
+            function: "get".into(),
+            cap_id: CAP_HTTP,
+            fn_id: HTTP_GET,
+            args: vec![url],
+        })
+    );
+}
+
+#[test]
+fn non_capability_errors_have_no_request() {
+    let err = HttpModule::new().call("get", vec![]).unwrap_err();
+    assert_eq!(err.as_capability_request(), None);
+    assert_eq!(
+        StdlibError::RuntimeError("boom".into()).as_capability_request(),
+        None
+    );
+}
+
+#[test]
+fn try_call_still_surfaces_capability_calls() {
+    let http = HttpModule::new();
+    let err = http
+        .try_call("get", vec![Value::String("https://example.com".into())])
+        .unwrap_err();
+    assert_eq!(err.as_capability_request().unwrap().fn_id, HTTP_GET);
+    // Argument errors are recoverable and come back as Err values
+    let result = http.try_call("get", vec![]).unwrap();
+    assert_eq!(result.type_name(), "result");
+}
@@ -1,7 +1,9 @@
 //! Integration tests for `pepl-stdlib` Phase 1: scaffolding + core module.
 
+#![allow(clippy::approx_constant)] // 3.14 etc. are arbitrary decimals, not PI
+
 use pepl_stdlib::modules::core::CoreModule;
-use pepl_stdlib::{StdlibError, StdlibModule, Value};
+use pepl_stdlib::{RecordBuilder, ResultValue, StdlibError, StdlibFn, StdlibModule, Value};
 use std::collections::BTreeMap;
 
 // ── Helpers ───────────────────────────────────────────────────────────────────
@@ -191,6 +193,31 @@ fn test_value_equality_records_ignore_type_name() {
     assert_eq!(a, c); // named vs anonymous → equal
 }
 
+#[test]
+fn test_value_nominal_eq_compares_type_names() {
+    let fields = BTreeMap::from([("x".to_string(), Value::Number(1.0))]);
+    let foo = Value::named_record("Foo", fields.clone());
+    let bar = Value::named_record("Bar", fields.clone());
+    let anon = Value::record(fields.clone());
+    assert_eq!(foo, bar); // structurally equal
+    assert!(!foo.nominal_eq(&bar));
+    assert!(!foo.nominal_eq(&anon));
+    assert!(foo.nominal_eq(&Value::named_record("Foo", fields)));
+}
+
+#[test]
+fn test_value_nominal_eq_nested() {
+    let inner = |name: &str| Value::named_record(name, BTreeMap::new());
+    let a = Value::List(vec![inner("Foo").ok()]);
+    let b = Value::List(vec![inner("Bar").ok()]);
+    assert_eq!(a, b);
+    assert!(!a.nominal_eq(&b));
+    assert!(a.nominal_eq(&a.clone()));
+    // Non-record values behave like `==`
+    assert!(Value::Number(1.0).nominal_eq(&Value::Number(1.0)));
+    assert!(!Value::Number(f64::NAN).nominal_eq(&Value::Number(f64::NAN)));
+}
+
 #[test]
 fn test_value_equality_colors() {
     let c1 = Value::Color {
@@ -222,6 +249,53 @@ fn test_value_equality_results() {
     assert_ne!(Value::Number(1.0).ok(), Value::Number(2.0).ok());
 }
 
+#[test]
+fn test_approx_eq_nested_numbers() {
+    let a = Value::record_of(&[
+        (
+            "xs",
+            Value::list_of(&[Value::Number(0.1 + 0.2), Value::Number(1.0)]),
+        ),
+        ("r", Value::Number(2.0).ok()),
+    ]);
+    let b = Value::record_of(&[
+        (
+            "xs",
+            Value::list_of(&[Value::Number(0.3), Value::Number(1.0)]),
+        ),
+        ("r", Value::Number(2.0 + 1e-12).ok()),
+    ]);
+    assert_ne!(a, b);
+    assert!(a.approx_eq(&b, 1e-9));
+    assert!(!a.approx_eq(&b, 1e-20));
+    let v1 = Value::sum_variant("Shape", "Circle", vec![Value::Number(1.0)]);
+    let v2 = Value::sum_variant("Shape", "Circle", vec![Value::Number(1.0005)]);
+    assert!(v1.approx_eq(&v2, 1e-3));
+}
+
+#[test]
+fn test_approx_eq_structural_mismatch() {
+    let one = || Value::Number(1.0);
+    assert!(!one().approx_eq(&Value::String("1".into()), 1.0));
+
+    let short = Value::list_of(&[one()]);
+    let long = Value::list_of(&[one(), one()]);
+    assert!(!short.approx_eq(&long, 1.0));
+
+    let a = Value::record_of(&[("a", one())]);
+    let b = Value::record_of(&[("b", one())]);
+    assert!(!a.approx_eq(&b, 1.0));
+
+    assert!(!one().ok().approx_eq(&one().err(), 1.0));
+
+    let circle = Value::sum_variant("Shape", "Circle", vec![one()]);
+    let square = Value::sum_variant("Shape", "Square", vec![one()]);
+    assert!(!circle.approx_eq(&square, 1.0));
+
+    // Non-numbers compare exactly
+    assert!(Value::String("a".into()).approx_eq(&Value::String("a".into()), 0.0));
+}
+
 // ── Truthiness tests ──────────────────────────────────────────────────────────
 
 #[test]
@@ -277,6 +351,53 @@ fn test_value_as_record() {
     assert_eq!(Value::Nil.as_record(), None);
 }
 
+#[test]
+fn test_value_as_color() {
+    let v = Value::Color {
+        r: 1.0,
+        g: 0.5,
+        b: 0.0,
+        a: 0.25,
+    };
+    assert_eq!(v.as_color(), Some((1.0, 0.5, 0.0, 0.25)));
+    assert_eq!(Value::Number(1.0).as_color(), None);
+}
+
+#[test]
+fn test_value_as_result() {
+    let ok = Value::Number(1.0).ok();
+    assert_eq!(ok.as_result(), Some(&ResultValue::Ok(Value::Number(1.0))));
+    let err = Value::String("bad".into()).err();
+    assert_eq!(
+        err.as_result(),
+        Some(&ResultValue::Err(Value::String("bad".into())))
+    );
+    assert_eq!(Value::Nil.as_result(), None);
+}
+
+#[test]
+fn test_function_arity() {
+    let plain = Value::Function(StdlibFn::new(|_| Ok(Value::Nil)));
+    assert_eq!(plain.function_arity(), None);
+    let binary = StdlibFn::new(|_| Ok(Value::Nil)).with_arity(2);
+    assert_eq!(binary.arity(), Some(2));
+    assert_eq!(Value::Function(binary).function_arity(), Some(2));
+    assert_eq!(Value::Number(1.0).function_arity(), None);
+}
+
+#[test]
+fn test_function_from_arc() {
+    let closure: std::sync::Arc<dyn Fn(Vec<Value>) -> Result<Value, StdlibError> + Send + Sync> =
+        std::sync::Arc::new(|args| Ok(args[0].clone()));
+    let f = StdlibFn::from(closure.clone());
+    assert_eq!(f.arity(), None);
+    assert_eq!(
+        f.call(vec![Value::Number(1.0)]).unwrap(),
+        Value::Number(1.0)
+    );
+    assert_eq!(f, StdlibFn::from(closure));
+}
+
 // ── From impls ────────────────────────────────────────────────────────────────
 
 #[test]
@@ -309,6 +430,233 @@ fn test_value_from_bool() {
     assert_eq!(v, Value::Bool(true));
 }
 
+// ── Builders ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_value_record_of_matches_manual() {
+    let mut fields = BTreeMap::new();
+    fields.insert("x".to_string(), Value::Number(1.0));
+    fields.insert("y".to_string(), Value::String("two".into()));
+    let manual = Value::record(fields);
+
+    let built = Value::record_of(&[("x", 1.into()), ("y", "two".into())]);
+    assert_eq!(built, manual);
+    assert_eq!(built.type_name(), "record");
+}
+
+#[test]
+fn test_value_list_of_matches_manual() {
+    let manual = Value::List(vec![Value::Number(1.0), Value::Bool(true)]);
+    assert_eq!(Value::list_of(&[1.into(), true.into()]), manual);
+    assert_eq!(Value::list_of(&[]), Value::List(vec![]));
+}
+
+#[test]
+fn test_record_builder_matches_manual() {
+    let mut fields = BTreeMap::new();
+    fields.insert("title".to_string(), Value::String("Buy milk".into()));
+    fields.insert("done".to_string(), Value::Bool(false));
+    let manual = Value::named_record("Todo", fields);
+
+    let built = RecordBuilder::named("Todo")
+        .field("title", "Buy milk")
+        .field("done", false)
+        .build();
+    assert_eq!(built, manual);
+    assert_eq!(built.declared_type_name(), Some("Todo"));
+}
+
+#[test]
+fn test_record_builder_anonymous_overwrites() {
+    let built = RecordBuilder::new()
+        .field("a", 1i64)
+        .field("a", 2i64)
+        .build();
+    assert_eq!(built, Value::record_of(&[("a", 2.into())]));
+    assert_eq!(built.declared_type_name(), None);
+}
+
+// ── Total ordering ────────────────────────────────────────────────────────────
+
+#[test]
+fn test_total_cmp_within_type() {
+    use std::cmp::Ordering;
+    let n = |x: f64| Value::Number(x);
+    let s = |x: &str| Value::String(x.into());
+    assert_eq!(n(1.0).total_cmp(&n(2.0)), Ordering::Less);
+    assert_eq!(n(2.0).total_cmp(&n(2.0)), Ordering::Equal);
+    assert_eq!(s("b").total_cmp(&s("a")), Ordering::Greater);
+    assert_eq!(
+        Value::Bool(false).total_cmp(&Value::Bool(true)),
+        Ordering::Less
+    );
+    assert_eq!(
+        Value::list_of(&[n(1.0), n(2.0)]).total_cmp(&Value::list_of(&[n(1.0), n(3.0)])),
+        Ordering::Less
+    );
+    assert_eq!(
+        Value::list_of(&[n(1.0)]).total_cmp(&Value::list_of(&[n(1.0), n(0.0)])),
+        Ordering::Less
+    );
+    assert_eq!(
+        Value::Number(1.0).ok().total_cmp(&Value::Number(0.0).err()),
+        Ordering::Less
+    );
+}
+
+#[test]
+fn test_total_cmp_cross_type() {
+    let ordered = [
+        Value::Nil,
+        Value::Bool(true),
+        Value::Number(-100.0),
+        Value::String("".into()),
+        Value::List(vec![]),
+        Value::record_of(&[]),
+        Value::Color {
+            r: 0.0,
+            g: 0.0,
+            b: 0.0,
+            a: 0.0,
+        },
+        Value::Nil.ok(),
+        Value::unit_variant("Status", "Active"),
+    ];
+    for pair in ordered.windows(2) {
+        assert_eq!(
+            pair[0].total_cmp(&pair[1]),
+            std::cmp::Ordering::Less,
+            "{:?} should sort before {:?}",
+            pair[0],
+            pair[1]
+        );
+    }
+}
+
+#[test]
+fn test_total_cmp_is_consistent_total_order() {
+    let values = vec![
+        Value::Number(3.0),
+        Value::String("a".into()),
+        Value::Nil,
+        Value::Number(-1.0),
+        Value::Bool(false),
+        Value::record_of(&[("x", Value::Number(1.0))]),
+        Value::list_of(&[Value::Number(1.0)]),
+    ];
+    for a in &values {
+        assert_eq!(a.total_cmp(a), std::cmp::Ordering::Equal);
+        for b in &values {
+            // Antisymmetry
+            assert_eq!(a.total_cmp(b), b.total_cmp(a).reverse());
+            for c in &values {
+                // Transitivity
+                if a.total_cmp(b).is_le() && b.total_cmp(c).is_le() {
+                    assert!(a.total_cmp(c).is_le());
+                }
+            }
+        }
+    }
+}
+
+// ── Canonical encoding ────────────────────────────────────────────────────────
+
+#[test]
+fn test_canonical_string_equal_values_match() {
+    let build = || {
+        RecordBuilder::named("User")
+            .field("name", "Ada")
+            .field("tags", Value::list_of(&[Value::from("x"), Value::Nil]))
+            .field("score", Value::Number(1.5).ok())
+            .build()
+    };
+    assert_eq!(build().canonical_string(), build().canonical_string());
+    // Records compare structurally, so type_name doesn't affect the encoding
+    let anon = Value::record_of(&[
+        ("name", Value::from("Ada")),
+        ("tags", Value::list_of(&[Value::from("x"), Value::Nil])),
+        ("score", Value::Number(1.5).ok()),
+    ]);
+    assert_eq!(build().canonical_string(), anon.canonical_string());
+    assert_eq!(
+        Value::Number(0.0).canonical_string(),
+        Value::Number(-0.0).canonical_string()
+    );
+}
+
+#[test]
+fn test_canonical_string_distinguishes_types() {
+    let values = [
+        Value::Nil,
+        Value::String("nil".into()),
+        Value::Number(1.0),
+        Value::String("1".into()),
+        Value::Bool(true),
+        Value::String("true".into()),
+        Value::list_of(&[Value::Number(1.0)]),
+        Value::Number(1.0).ok(),
+        Value::Number(1.0).err(),
+        Value::unit_variant("Status", "Active"),
+        Value::unit_variant("Mode", "Active"),
+        Value::Color {
+            r: 1.0,
+            g: 0.0,
+            b: 0.0,
+            a: 1.0,
+        },
+    ];
+    for (i, a) in values.iter().enumerate() {
+        for b in &values[i + 1..] {
+            assert_ne!(a.canonical_string(), b.canonical_string(), "{a:?} vs {b:?}");
+        }
+    }
+}
+
+#[test]
+fn test_canonical_string_escapes_strings() {
+    // Without escaping, these two lists would encode identically
+    let one = Value::list_of(&[Value::String("a\",s:\"b".into())]);
+    let two = Value::list_of(&[Value::String("a".into()), Value::String("b".into())]);
+    assert_ne!(one.canonical_string(), two.canonical_string());
+    assert_eq!(
+        Value::String("say \"hi\"".into()).canonical_string(),
+        r#"s:"say \"hi\"""#
+    );
+}
+
+// ── Size estimate ─────────────────────────────────────────────────────────────
+
+#[test]
+fn test_size_hint_primitives_are_constant() {
+    let base = Value::Nil.size_hint();
+    assert!(base > 0);
+    assert_eq!(Value::Number(1.0).size_hint(), base);
+    assert_eq!(Value::Bool(true).size_hint(), base);
+    assert_eq!(Value::String(String::new()).size_hint(), base);
+}
+
+#[test]
+fn test_size_hint_relative_ordering() {
+    let short = Value::String("hi".into());
+    let long = Value::String("x".repeat(1000));
+    assert!(long.size_hint() > short.size_hint());
+
+    let small = Value::List(vec![Value::Number(1.0)]);
+    let large = Value::List((0..100).map(|i| Value::Number(i as f64)).collect());
+    assert!(large.size_hint() > small.size_hint());
+}
+
+#[test]
+fn test_size_hint_recurses_into_containers() {
+    let payload = Value::String("x".repeat(500));
+    let base = Value::Nil.size_hint();
+    let record = Value::record_of(&[("k", payload.clone())]);
+    assert!(record.size_hint() >= base + payload.size_hint());
+    assert!(payload.clone().ok().size_hint() > payload.size_hint());
+    let variant = Value::sum_variant("Shape", "Text", vec![payload.clone()]);
+    assert!(variant.size_hint() > payload.size_hint());
+}
+
 // ══════════════════════════════════════════════════════════════════════════════
 // core.log tests
 // ══════════════════════════════════════════════════════════════════════════════
@@ -493,6 +841,146 @@ fn test_core_type_of_wrong_arg_count() {
     assert!(matches!(err, StdlibError::WrongArgCount { .. }));
 }
 
+// ══════════════════════════════════════════════════════════════════════════════
+// core.assert_type tests
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_core_assert_type_matches() {
+    let result = core()
+        .call(
+            "assert_type",
+            vec![Value::Number(1.0), Value::String("number".into())],
+        )
+        .unwrap();
+    assert_eq!(result, Value::Nil);
+}
+
+#[test]
+fn test_core_assert_type_mismatch() {
+    let err = core()
+        .call(
+            "assert_type",
+            vec![Value::String("1".into()), Value::String("number".into())],
+        )
+        .unwrap_err();
+    match err {
+        StdlibError::TypeMismatch { expected, got, .. } => {
+            assert_eq!(expected, "number");
+            assert_eq!(got, "string");
+        }
+        other => panic!("expected TypeMismatch, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_core_assert_type_named_record() {
+    let todo = RecordBuilder::named("Todo").field("done", false).build();
+    for expected in ["Todo", "record"] {
+        let result = core().call(
+            "assert_type",
+            vec![todo.clone(), Value::String(expected.into())],
+        );
+        assert_eq!(result.unwrap(), Value::Nil);
+    }
+    let err = core()
+        .call("assert_type", vec![todo, Value::String("User".into())])
+        .unwrap_err();
+    assert!(matches!(err, StdlibError::TypeMismatch { .. }));
+}
+
+#[test]
+fn test_core_assert_type_sum_variant() {
+    let status = Value::unit_variant("Status", "Active");
+    for expected in ["Status", "variant"] {
+        let result = core().call(
+            "assert_type",
+            vec![status.clone(), Value::String(expected.into())],
+        );
+        assert_eq!(result.unwrap(), Value::Nil);
+    }
+}
+
+#[test]
+fn test_core_assert_type_bad_args() {
+    let err = core()
+        .call("assert_type", vec![Value::Nil, Value::Nil])
+        .unwrap_err();
+    assert!(matches!(err, StdlibError::TypeMismatch { position: 2, .. }));
+    let err = core().call("assert_type", vec![Value::Nil]).unwrap_err();
+    assert!(matches!(err, StdlibError::WrongArgCount { .. }));
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// core.trace tests
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_core_trace_returns_value() {
+    let value = Value::record_of(&[("x", Value::Number(1.0))]);
+    let result = core()
+        .call("trace", vec![Value::String("point".into()), value.clone()])
+        .unwrap();
+    assert_eq!(result, value);
+}
+
+#[test]
+fn test_core_trace_rejects_non_string_label() {
+    let err = core()
+        .call("trace", vec![Value::Number(1.0), Value::Nil])
+        .unwrap_err();
+    assert!(matches!(err, StdlibError::TypeMismatch { position: 1, .. }));
+}
+
+#[test]
+fn test_core_trace_wrong_arg_count() {
+    let err = core()
+        .call("trace", vec![Value::String("only label".into())])
+        .unwrap_err();
+    assert!(matches!(err, StdlibError::WrongArgCount { .. }));
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// core.mark_start / core.mark_end tests
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_core_marks_return_nil() {
+    let c = core();
+    for func in ["mark_start", "mark_end"] {
+        let result = c.call(func, vec![Value::String("render".into())]).unwrap();
+        assert_eq!(result, Value::Nil, "core.{func}");
+    }
+}
+
+#[test]
+fn test_core_marks_reject_non_string_label() {
+    let c = core();
+    for func in ["mark_start", "mark_end"] {
+        let err = c.call(func, vec![Value::Number(1.0)]).unwrap_err();
+        assert!(
+            matches!(err, StdlibError::TypeMismatch { position: 1, .. }),
+            "core.{func}"
+        );
+    }
+}
+
+#[test]
+fn test_core_marks_wrong_arg_count() {
+    let c = core();
+    for func in ["mark_start", "mark_end"] {
+        assert!(matches!(
+            c.call(func, vec![]).unwrap_err(),
+            StdlibError::WrongArgCount { .. }
+        ));
+        let two = vec![Value::String("a".into()), Value::Nil];
+        assert!(matches!(
+            c.call(func, two).unwrap_err(),
+            StdlibError::WrongArgCount { .. }
+        ));
+    }
+}
+
 // ══════════════════════════════════════════════════════════════════════════════
 // core.capability tests
 // ══════════════════════════════════════════════════════════════════════════════
@@ -526,6 +1014,36 @@ fn test_core_capability_any_name_returns_false() {
     }
 }
 
+#[test]
+fn test_core_capability_with_allow_list() {
+    let c = CoreModule::with_capabilities(&["http"]);
+    let check = |name: &str| c.call("capability", vec![Value::String(name.into())]);
+    assert_eq!(check("http").unwrap(), Value::Bool(true));
+    assert_eq!(check("storage").unwrap(), Value::Bool(false));
+    assert_eq!(check("HTTP").unwrap(), Value::Bool(false));
+    // Deterministic per instance
+    for _ in 0..10 {
+        assert_eq!(check("http").unwrap(), Value::Bool(true));
+    }
+}
+
+#[test]
+fn test_core_capability_default_matches_empty_allow_list() {
+    let configured = CoreModule::with_capabilities(&[]);
+    let default = CoreModule::default();
+    for name in ["http", "storage", "location"] {
+        let args = vec![Value::String(name.into())];
+        assert_eq!(
+            configured.call("capability", args.clone()).unwrap(),
+            Value::Bool(false)
+        );
+        assert_eq!(
+            default.call("capability", args).unwrap(),
+            Value::Bool(false)
+        );
+    }
+}
+
 #[test]
 fn test_core_capability_type_mismatch() {
     let err = core()
@@ -556,6 +1074,10 @@ fn test_core_has_function() {
     assert!(c.has_function("assert"));
     assert!(c.has_function("type_of"));
     assert!(c.has_function("capability"));
+    assert!(c.has_function("assert_type"));
+    assert!(c.has_function("trace"));
+    assert!(c.has_function("mark_start"));
+    assert!(c.has_function("mark_end"));
     assert!(!c.has_function("nonexistent"));
     assert!(!c.has_function(""));
 }
@@ -566,6 +1088,35 @@ fn test_core_unknown_function() {
     assert!(matches!(err, StdlibError::UnknownFunction { .. }));
 }
 
+#[test]
+fn test_try_call_wraps_success_in_ok() {
+    let result = core().try_call("type_of", vec![Value::Nil]).unwrap();
+    assert_eq!(result, Value::String("nil".into()).ok());
+}
+
+#[test]
+fn test_try_call_turns_recoverable_errors_into_err_values() {
+    let c = core();
+    let type_error = c.try_call("capability", vec![Value::Number(1.0)]).unwrap();
+    assert_eq!(
+        type_error,
+        Value::String("core.capability: argument 1 expected string, got number".into()).err()
+    );
+    let unknown = c.try_call("nonexistent", vec![]).unwrap();
+    assert!(matches!(
+        unknown.as_result(),
+        Some(ResultValue::Err(Value::String(msg))) if msg.contains("core.nonexistent")
+    ));
+}
+
+#[test]
+fn test_try_call_keeps_assertion_trap() {
+    let err = core()
+        .try_call("assert", vec![Value::Bool(false)])
+        .unwrap_err();
+    assert!(matches!(err, StdlibError::AssertionFailed { .. }));
+}
+
 // ══════════════════════════════════════════════════════════════════════════════
 // Error display tests
 // ══════════════════════════════════════════════════════════════════════════════
@@ -599,6 +1150,51 @@ fn test_error_display_assertion_failed() {
     assert_eq!(format!("{err}"), "Assertion failed: x > 0");
 }
 
+// ── Error codes ───────────────────────────────────────────────────────────────
+
+#[test]
+fn test_error_codes_per_variant() {
+    assert_eq!(
+        StdlibError::wrong_args("core.log", 1, 0).code(),
+        "wrong_arg_count"
+    );
+    assert_eq!(
+        StdlibError::type_mismatch("core.assert", 1, "bool", "number").code(),
+        "type_mismatch"
+    );
+    assert_eq!(
+        StdlibError::unknown_function("core", "foo").code(),
+        "unknown_function"
+    );
+    assert_eq!(
+        StdlibError::AssertionFailed {
+            message: "x".into()
+        }
+        .code(),
+        "assertion_failed"
+    );
+    assert_eq!(
+        StdlibError::RuntimeError("boom".into()).code(),
+        "runtime_error"
+    );
+    assert_eq!(
+        StdlibError::capability_call("http", "get", 1, 1, vec![]).code(),
+        "capability_call"
+    );
+}
+
+#[test]
+fn test_error_codes_from_real_calls() {
+    let err = core().call("type_of", vec![]).unwrap_err();
+    assert_eq!(err.code(), "wrong_arg_count");
+    let err = core().call("assert", vec![Value::Number(1.0)]).unwrap_err();
+    assert_eq!(err.code(), "type_mismatch");
+    let err = core().call("assert", vec![Value::Bool(false)]).unwrap_err();
+    assert_eq!(err.code(), "assertion_failed");
+    let err = core().call("nope", vec![]).unwrap_err();
+    assert_eq!(err.code(), "unknown_function");
+}
+
 // ══════════════════════════════════════════════════════════════════════════════
 // SumVariant tests
 // ══════════════════════════════════════════════════════════════════════════════
@@ -0,0 +1,149 @@
+//! Tests for the `csv` stdlib module.
+
+use pepl_stdlib::modules::csv::CsvModule;
+use pepl_stdlib::{StdlibError, StdlibModule, Value};
+
+// ── Helpers ──────────────────────────────────────────────────────────────────
+
+fn call(func: &str, args: Vec<Value>) -> Result<Value, StdlibError> {
+    CsvModule::new().call(func, args)
+}
+
+fn s(v: &str) -> Value {
+    Value::String(v.to_string())
+}
+
+fn strs(items: &[&str]) -> Value {
+    Value::List(items.iter().map(|v| s(v)).collect())
+}
+
+fn parse(line: &str) -> Value {
+    call("parse_line", vec![s(line)]).unwrap()
+}
+
+fn format(fields: &[&str]) -> Value {
+    call("format_line", vec![strs(fields)]).unwrap()
+}
+
+// ── csv.parse_line ───────────────────────────────────────────────────────────
+
+#[test]
+fn parse_plain_fields() {
+    assert_eq!(parse("a,b,c"), strs(&["a", "b", "c"]));
+    assert_eq!(parse("one"), strs(&["one"]));
+}
+
+#[test]
+fn parse_quoted_fields_with_commas() {
+    assert_eq!(
+        parse(r#"name,"Smith, Jane",42"#),
+        strs(&["name", "Smith, Jane", "42"])
+    );
+    assert_eq!(parse("\"line\nbreak\",x"), strs(&["line\nbreak", "x"]));
+}
+
+#[test]
+fn parse_escaped_quotes() {
+    assert_eq!(parse(r#""say ""hi""",x"#), strs(&[r#"say "hi""#, "x"]));
+    assert_eq!(parse(r#""""""#), strs(&[r#"""#]));
+    // Bare quote inside an unquoted field is literal
+    assert_eq!(parse(r#"5" disk,x"#), strs(&[r#"5" disk"#, "x"]));
+}
+
+#[test]
+fn parse_empty_fields() {
+    assert_eq!(parse(""), strs(&[""]));
+    assert_eq!(parse(",,"), strs(&["", "", ""]));
+    assert_eq!(parse(r#"a,"",b,"#), strs(&["a", "", "b", ""]));
+}
+
+#[test]
+fn parse_ignores_trailing_line_break() {
+    assert_eq!(parse("a,b\n"), strs(&["a", "b"]));
+    assert_eq!(parse("a,b\r\n"), strs(&["a", "b"]));
+}
+
+#[test]
+fn parse_malformed_quotes_trap() {
+    for line in [r#""open,b"#, r#""a"b,c"#] {
+        assert!(
+            matches!(
+                call("parse_line", vec![s(line)]),
+                Err(StdlibError::RuntimeError(_))
+            ),
+            "{line}"
+        );
+    }
+}
+
+// ── csv.format_line ──────────────────────────────────────────────────────────
+
+#[test]
+fn format_plain_fields() {
+    assert_eq!(format(&["a", "b", "c"]), s("a,b,c"));
+    assert_eq!(format(&["", "x", ""]), s(",x,"));
+    assert_eq!(format(&[]), s(""));
+}
+
+#[test]
+fn format_quotes_when_needed() {
+    assert_eq!(
+        format(&["Smith, Jane", r#"say "hi""#, "a\nb", "plain"]),
+        s("\"Smith, Jane\",\"say \"\"hi\"\"\",\"a\nb\",plain")
+    );
+}
+
+#[test]
+fn format_rejects_non_strings() {
+    let fields = Value::List(vec![s("a"), Value::Number(1.0)]);
+    assert!(matches!(
+        call("format_line", vec![fields]),
+        Err(StdlibError::RuntimeError(_))
+    ));
+}
+
+#[test]
+fn format_parse_round_trip() {
+    let fields: &[&str] = &[
+        "id",
+        "",
+        "comma, inside",
+        r#"quote""d"#,
+        "multi\r\nline",
+        " spaced ",
+    ];
+    let line = format(fields);
+    assert_eq!(call("parse_line", vec![line]).unwrap(), strs(fields));
+}
+
+// ── Module ───────────────────────────────────────────────────────────────────
+
+#[test]
+fn csv_has_function() {
+    let m = CsvModule::new();
+    assert_eq!(m.name(), "csv");
+    for f in ["parse_line", "format_line"] {
+        assert!(m.has_function(f), "csv should have function {f}");
+    }
+    assert!(!m.has_function("parse"));
+}
+
+#[test]
+fn csv_wrong_args() {
+    assert!(matches!(
+        call("parse_line", vec![]),
+        Err(StdlibError::WrongArgCount { .. })
+    ));
+    assert!(matches!(
+        call("format_line", vec![s("a,b")]),
+        Err(StdlibError::TypeMismatch { .. })
+    ));
+}
+
+#[test]
+fn csv_unknown_function() {
+    assert!(matches!(
+        call("nope", vec![]),
+        Err(StdlibError::UnknownFunction { .. })
+    ));
+}
@@ -0,0 +1,136 @@
+//! Tests for the `encoding` stdlib module.
+
+use pepl_stdlib::modules::encoding::EncodingModule;
+use pepl_stdlib::{ResultValue, StdlibError, StdlibModule, Value};
+
+// ── Helpers ──────────────────────────────────────────────────────────────────
+
+fn call(func: &str, args: Vec<Value>) -> Result<Value, StdlibError> {
+    EncodingModule::new().call(func, args)
+}
+
+fn bytes(items: &[u8]) -> Value {
+    Value::List(items.iter().map(|b| Value::Number(f64::from(*b))).collect())
+}
+
+fn s(v: &str) -> Value {
+    Value::String(v.to_string())
+}
+
+fn unwrap_ok(v: Value) -> Value {
+    match v {
+        Value::Result(rv) => match *rv {
+            ResultValue::Ok(inner) => inner,
+            ResultValue::Err(e) => panic!("expected Ok, got Err({e})"),
+        },
+        other => panic!("expected Result, got {other}"),
+    }
+}
+
+fn is_err(v: &Value) -> bool {
+    matches!(v, Value::Result(rv) if matches!(**rv, ResultValue::Err(_)))
+}
+
+// ── encoding.to_hex ──────────────────────────────────────────────────────────
+
+#[test]
+fn to_hex_basic() {
+    assert_eq!(
+        call("to_hex", vec![bytes(&[0, 15, 16, 171, 255])]).unwrap(),
+        s("000f10abff")
+    );
+}
+
+#[test]
+fn to_hex_empty() {
+    assert_eq!(call("to_hex", vec![bytes(&[])]).unwrap(), s(""));
+}
+
+#[test]
+fn to_hex_rejects_non_bytes() {
+    for bad in [256.0, -1.0, 1.5] {
+        let err = call("to_hex", vec![Value::List(vec![Value::Number(bad)])]);
+        assert!(matches!(err, Err(StdlibError::RuntimeError(_))));
+    }
+    let err = call("to_hex", vec![Value::List(vec![s("a")])]);
+    assert!(matches!(err, Err(StdlibError::RuntimeError(_))));
+    let err = call("to_hex", vec![s("ab")]);
+    assert!(matches!(err, Err(StdlibError::TypeMismatch { .. })));
+}
+
+// ── encoding.from_hex ────────────────────────────────────────────────────────
+
+#[test]
+fn from_hex_lowercase_and_uppercase() {
+    let expected = bytes(&[222, 173, 190, 239]);
+    assert_eq!(
+        unwrap_ok(call("from_hex", vec![s("deadbeef")]).unwrap()),
+        expected
+    );
+    assert_eq!(
+        unwrap_ok(call("from_hex", vec![s("DEADBEEF")]).unwrap()),
+        expected
+    );
+    assert_eq!(
+        unwrap_ok(call("from_hex", vec![s("DeAdBeEf")]).unwrap()),
+        expected
+    );
+}
+
+#[test]
+fn from_hex_empty() {
+    assert_eq!(
+        unwrap_ok(call("from_hex", vec![s("")]).unwrap()),
+        bytes(&[])
+    );
+}
+
+#[test]
+fn from_hex_odd_length_is_err() {
+    assert!(is_err(&call("from_hex", vec![s("abc")]).unwrap()));
+}
+
+#[test]
+fn from_hex_invalid_digit_is_err() {
+    assert!(is_err(&call("from_hex", vec![s("zz")]).unwrap()));
+    assert!(is_err(&call("from_hex", vec![s("0g")]).unwrap()));
+    assert!(is_err(&call("from_hex", vec![s("é0")]).unwrap()));
+}
+
+#[test]
+fn hex_round_trip() {
+    let all: Vec<u8> = (0..=255).collect();
+    let hex = call("to_hex", vec![bytes(&all)]).unwrap();
+    assert_eq!(unwrap_ok(call("from_hex", vec![hex]).unwrap()), bytes(&all));
+}
+
+// ── Module ───────────────────────────────────────────────────────────────────
+
+#[test]
+fn encoding_has_function() {
+    let m = EncodingModule::new();
+    assert_eq!(m.name(), "encoding");
+    assert!(m.has_function("to_hex"));
+    assert!(m.has_function("from_hex"));
+    assert!(!m.has_function("to_base64"));
+}
+
+#[test]
+fn encoding_wrong_arg_count() {
+    assert!(matches!(
+        call("to_hex", vec![]),
+        Err(StdlibError::WrongArgCount { .. })
+    ));
+    assert!(matches!(
+        call("from_hex", vec![s("00"), s("00")]),
+        Err(StdlibError::WrongArgCount { .. })
+    ));
+}
+
+#[test]
+fn encoding_unknown_function() {
+    assert!(matches!(
+        call("nope", vec![]),
+        Err(StdlibError::UnknownFunction { .. })
+    ));
+}
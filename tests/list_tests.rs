@@ -1,4 +1,4 @@
-//! Tests for the `list` module — 32 functions (31 spec + 5 extensions + `some` alias).
+//! Tests for the `list` module — 66 functions plus the `set` and `some` aliases.
 //!
 //! Each function gets:
 //! - Normal-case tests (1–3)
@@ -210,6 +210,112 @@ fn range_wrong_type() {
 // Access
 // ═══════════════════════════════════════════════════════════════════════════════
 
+// ── list.repeat_with ──────────────────────────────────────────────────────────
+
+fn square() -> Value {
+    pred_fn(|args| match &args[0] {
+        Value::Number(n) => Ok(num(n * n)),
+        _ => Ok(Value::Nil),
+    })
+}
+
+#[test]
+fn repeat_with_squares() {
+    assert_eq!(
+        call_ok("repeat_with", vec![num(4.0), square()]),
+        lst(vec![num(0.0), num(1.0), num(4.0), num(9.0)])
+    );
+}
+
+#[test]
+fn repeat_with_zero_count() {
+    assert_eq!(
+        call_ok("repeat_with", vec![num(0.0), square()]),
+        lst(vec![])
+    );
+}
+
+#[test]
+fn repeat_with_size_limit() {
+    assert!(call("repeat_with", vec![num(10_000_001.0), square()]).is_err());
+    assert!(call("repeat_with", vec![num(-1.0), square()]).is_err());
+    assert!(call("repeat_with", vec![num(1.5), square()]).is_err());
+}
+
+#[test]
+fn repeat_with_propagates_callback_error() {
+    let failing = pred_fn(|_| Err(StdlibError::RuntimeError("boom".into())));
+    assert!(call("repeat_with", vec![num(3.0), failing]).is_err());
+}
+
+#[test]
+fn repeat_with_rejects_callback_with_wrong_arity() {
+    let two_args = Value::Function(StdlibFn::new(|args| Ok(args[0].clone())).with_arity(2));
+    let err = call("repeat_with", vec![num(3.0), two_args]).unwrap_err();
+    assert!(matches!(err, StdlibError::RuntimeError(msg) if msg.contains("takes 2 arguments,")));
+}
+
+// ── list.range_map_filter ─────────────────────────────────────────────────────
+
+#[test]
+fn range_map_filter_matches_naive_chain() {
+    let naive = {
+        let range = call_ok("range", vec![num(-5.0), num(20.0)]);
+        let mapped = call_ok("map", vec![range, double()]);
+        call_ok("filter", vec![mapped, gt(10.0)])
+    };
+    let fused = call_ok(
+        "range_map_filter",
+        vec![num(-5.0), num(20.0), double(), gt(10.0)],
+    );
+    assert_eq!(fused, naive);
+}
+
+#[test]
+fn range_map_filter_large_range() {
+    let every_100k = pred_fn(|args| Ok(b(args[0].as_number().unwrap() % 100_000.0 == 0.0)));
+    let result = call_ok(
+        "range_map_filter",
+        vec![num(0.0), num(1_000_000.0), identity(), every_100k],
+    );
+    assert_eq!(result.as_list().unwrap().len(), 10);
+    assert_eq!(result.as_list().unwrap()[9], num(900_000.0));
+}
+
+#[test]
+fn range_map_filter_empty_and_limits() {
+    assert_eq!(
+        call_ok(
+            "range_map_filter",
+            vec![num(5.0), num(1.0), double(), gt(0.0)]
+        ),
+        lst(vec![])
+    );
+    let err = call(
+        "range_map_filter",
+        vec![num(0.0), num(20_000_000.0), double(), gt(0.0)],
+    )
+    .unwrap_err();
+    assert!(matches!(err, StdlibError::RuntimeError(msg) if msg.contains("too large")));
+}
+
+#[test]
+fn range_map_filter_span_overflow() {
+    for (start, end) in [
+        (-1e19, 3.0),
+        (-1e19, 1e19),
+        (i64::MIN as f64, i64::MAX as f64),
+    ] {
+        let err = call(
+            "range_map_filter",
+            vec![num(start), num(end), double(), gt(0.0)],
+        )
+        .unwrap_err();
+        assert!(matches!(err, StdlibError::RuntimeError(msg) if msg.contains("too large")));
+        assert!(call("range", vec![num(start), num(end)]).is_err());
+    }
+}
+
 // ── list.length ───────────────────────────────────────────────────────────────
 
 #[test]
@@ -437,6 +543,33 @@ fn update_out_of_bounds() {
     assert!(call("update", vec![items, num(5.0), num(2.0)]).is_err());
 }
 
+// ── list.swap ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn swap_basic() {
+    assert_eq!(
+        call_ok("swap", vec![nums(&[1.0, 2.0, 3.0]), num(0.0), num(2.0)]),
+        nums(&[3.0, 2.0, 1.0])
+    );
+}
+
+#[test]
+fn swap_out_of_bounds() {
+    let err = call("swap", vec![nums(&[1.0, 2.0]), num(0.0), num(2.0)]).unwrap_err();
+    assert!(matches!(err, StdlibError::RuntimeError(msg) if msg.contains("out of bounds")));
+    assert!(call("swap", vec![nums(&[1.0, 2.0]), num(-1.0), num(0.0)]).is_err());
+    assert!(call("swap", vec![nums(&[1.0, 2.0]), num(0.5), num(1.0)]).is_err());
+}
+
+#[test]
+fn swap_with_itself() {
+    let items = nums(&[1.0, 2.0, 3.0]);
+    assert_eq!(
+        call_ok("swap", vec![items.clone(), num(1.0), num(1.0)]),
+        items
+    );
+}
+
 // ── list.slice ────────────────────────────────────────────────────────────────
 
 #[test]
@@ -509,6 +642,94 @@ fn concat_wrong_type() {
     assert!(call("concat", vec![lst(vec![]), num(1.0)]).is_err());
 }
 
+// ── list.concat_all ───────────────────────────────────────────────────────────
+
+#[test]
+fn concat_all_three_lists() {
+    let lists = lst(vec![
+        lst(vec![num(1.0)]),
+        lst(vec![num(2.0), num(3.0)]),
+        lst(vec![num(4.0)]),
+    ]);
+    assert_eq!(
+        call_ok("concat_all", vec![lists]),
+        lst(vec![num(1.0), num(2.0), num(3.0), num(4.0)])
+    );
+}
+
+#[test]
+fn concat_all_empty_element() {
+    let lists = lst(vec![lst(vec![num(1.0)]), lst(vec![]), lst(vec![num(2.0)])]);
+    assert_eq!(
+        call_ok("concat_all", vec![lists]),
+        lst(vec![num(1.0), num(2.0)])
+    );
+}
+
+#[test]
+fn concat_all_empty_outer() {
+    assert_eq!(call_ok("concat_all", vec![lst(vec![])]), lst(vec![]));
+}
+
+#[test]
+fn concat_all_non_list_element() {
+    let lists = lst(vec![lst(vec![num(1.0)]), num(2.0)]);
+    match call("concat_all", vec![lists]).unwrap_err() {
+        StdlibError::RuntimeError(msg) => assert!(msg.contains("element 1"), "{msg}"),
+        other => panic!("expected RuntimeError, got {other:?}"),
+    }
+}
+
+// ── list.interleave ───────────────────────────────────────────────────────────
+
+#[test]
+fn interleave_equal_lengths() {
+    assert_eq!(
+        call_ok("interleave", vec![nums(&[1.0, 3.0]), nums(&[2.0, 4.0])]),
+        nums(&[1.0, 2.0, 3.0, 4.0])
+    );
+}
+
+#[test]
+fn interleave_unequal_lengths() {
+    assert_eq!(
+        call_ok(
+            "interleave",
+            vec![nums(&[1.0, 3.0, 5.0, 6.0]), nums(&[2.0])]
+        ),
+        nums(&[1.0, 2.0, 3.0, 5.0, 6.0])
+    );
+    assert_eq!(
+        call_ok(
+            "interleave",
+            vec![lst(vec![s("a")]), lst(vec![num(1.0), num(2.0)])]
+        ),
+        lst(vec![s("a"), num(1.0), num(2.0)])
+    );
+}
+
+#[test]
+fn interleave_empty_operand() {
+    assert_eq!(
+        call_ok("interleave", vec![lst(vec![]), nums(&[1.0, 2.0])]),
+        nums(&[1.0, 2.0])
+    );
+    assert_eq!(
+        call_ok("interleave", vec![nums(&[1.0]), lst(vec![])]),
+        nums(&[1.0])
+    );
+    assert_eq!(
+        call_ok("interleave", vec![lst(vec![]), lst(vec![])]),
+        lst(vec![])
+    );
+}
+
+#[test]
+fn interleave_wrong_type() {
+    let err = call("interleave", vec![lst(vec![]), num(1.0)]).unwrap_err();
+    assert!(matches!(err, StdlibError::TypeMismatch { position: 2, .. }));
+}
+
 // ── list.reverse ──────────────────────────────────────────────────────────────
 
 #[test]
@@ -599,10 +820,215 @@ fn unique_preserves_order() {
     );
 }
 
+// ── list.dedup_consecutive ────────────────────────────────────────────────────
+
+#[test]
+fn dedup_consecutive_vs_unique() {
+    let items = lst(vec![num(1.0), num(1.0), num(2.0), num(1.0), num(1.0)]);
+    assert_eq!(
+        call_ok("dedup_consecutive", vec![items.clone()]),
+        lst(vec![num(1.0), num(2.0), num(1.0)])
+    );
+    assert_eq!(
+        call_ok("unique", vec![items]),
+        lst(vec![num(1.0), num(2.0)])
+    );
+}
+
+#[test]
+fn dedup_consecutive_empty() {
+    assert_eq!(call_ok("dedup_consecutive", vec![lst(vec![])]), lst(vec![]));
+}
+
+#[test]
+fn dedup_consecutive_all_equal() {
+    let items = lst(vec![s("a"), s("a"), s("a")]);
+    assert_eq!(call_ok("dedup_consecutive", vec![items]), lst(vec![s("a")]));
+}
+
+#[test]
+fn dedup_consecutive_wrong_type() {
+    assert!(call("dedup_consecutive", vec![num(1.0)]).is_err());
+}
+
+// ── list.without ──────────────────────────────────────────────────────────────
+
+#[test]
+fn without_removes_all_occurrences() {
+    let items = nums(&[1.0, 2.0, 1.0, 3.0, 1.0]);
+    assert_eq!(call_ok("without", vec![items, num(1.0)]), nums(&[2.0, 3.0]));
+}
+
+#[test]
+fn without_value_not_present() {
+    let items = nums(&[1.0, 2.0]);
+    assert_eq!(call_ok("without", vec![items.clone(), num(9.0)]), items);
+}
+
+#[test]
+fn without_empty_list() {
+    assert_eq!(call_ok("without", vec![lst(vec![]), num(1.0)]), lst(vec![]));
+}
+
+#[test]
+fn without_uses_structural_equality() {
+    let items = lst(vec![num(1.0), s("1"), lst(vec![num(1.0)])]);
+    assert_eq!(
+        call_ok("without", vec![items, lst(vec![num(1.0)])]),
+        lst(vec![num(1.0), s("1")])
+    );
+}
+
+// ── list.replace ──────────────────────────────────────────────────────────────
+
+#[test]
+fn replace_all_occurrences() {
+    let items = nums(&[1.0, 2.0, 1.0]);
+    assert_eq!(
+        call_ok("replace", vec![items, num(1.0), num(9.0)]),
+        nums(&[9.0, 2.0, 9.0])
+    );
+}
+
+#[test]
+fn replace_value_not_present() {
+    let items = nums(&[1.0, 2.0]);
+    assert_eq!(
+        call_ok("replace", vec![items.clone(), num(5.0), num(9.0)]),
+        items
+    );
+}
+
+#[test]
+fn replace_with_different_type() {
+    let items = lst(vec![num(0.0), s("a"), num(0.0)]);
+    assert_eq!(
+        call_ok("replace", vec![items, num(0.0), Value::Nil]),
+        lst(vec![Value::Nil, s("a"), Value::Nil])
+    );
+}
+
+#[test]
+fn replace_wrong_arg_count() {
+    assert!(call("replace", vec![lst(vec![]), num(1.0)]).is_err());
+}
+
+// ── list.shuffle ──────────────────────────────────────────────────────────────
+
+fn one_to_ten() -> Value {
+    lst((1..=10).map(|i| num(i as f64)).collect())
+}
+
+#[test]
+fn shuffle_same_seed_reproducible() {
+    let first = call_ok("shuffle", vec![one_to_ten(), num(42.0)]);
+    for _ in 0..10 {
+        assert_eq!(call_ok("shuffle", vec![one_to_ten(), num(42.0)]), first);
+    }
+}
+
+#[test]
+fn shuffle_preserves_elements() {
+    let shuffled = call_ok("shuffle", vec![one_to_ten(), num(7.0)]);
+    let sorted = call_ok("sort", vec![shuffled, cmp_asc()]);
+    assert_eq!(sorted, one_to_ten());
+}
+
+#[test]
+fn shuffle_different_seeds_differ() {
+    let a = call_ok("shuffle", vec![one_to_ten(), num(1.0)]);
+    let b = call_ok("shuffle", vec![one_to_ten(), num(2.0)]);
+    assert_ne!(a, b);
+    assert_ne!(a, one_to_ten());
+}
+
+#[test]
+fn shuffle_empty_and_single() {
+    assert_eq!(call_ok("shuffle", vec![lst(vec![]), num(0.0)]), lst(vec![]));
+    assert_eq!(
+        call_ok("shuffle", vec![lst(vec![num(1.0)]), num(0.0)]),
+        lst(vec![num(1.0)])
+    );
+}
+
+#[test]
+fn shuffle_wrong_args() {
+    assert!(call("shuffle", vec![one_to_ten()]).is_err());
+    assert!(call("shuffle", vec![one_to_ten(), s("seed")]).is_err());
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // Higher-Order
 // ═══════════════════════════════════════════════════════════════════════════════
 
+// ── list.splice ───────────────────────────────────────────────────────────────
+
+fn nums(values: &[f64]) -> Value {
+    lst(values.iter().map(|n| num(*n)).collect())
+}
+
+#[test]
+fn splice_pure_deletion() {
+    let result = call_ok(
+        "splice",
+        vec![nums(&[1.0, 2.0, 3.0, 4.0]), num(1.0), num(2.0), lst(vec![])],
+    );
+    assert_eq!(result, nums(&[1.0, 4.0]));
+}
+
+#[test]
+fn splice_pure_insertion() {
+    let result = call_ok(
+        "splice",
+        vec![nums(&[1.0, 4.0]), num(1.0), num(0.0), nums(&[2.0, 3.0])],
+    );
+    assert_eq!(result, nums(&[1.0, 2.0, 3.0, 4.0]));
+}
+
+#[test]
+fn splice_replacement() {
+    let result = call_ok(
+        "splice",
+        vec![
+            nums(&[1.0, 2.0, 3.0]),
+            num(1.0),
+            num(1.0),
+            nums(&[9.0, 9.0]),
+        ],
+    );
+    assert_eq!(result, nums(&[1.0, 9.0, 9.0, 3.0]));
+}
+
+#[test]
+fn splice_clamped_bounds() {
+    // start past the end appends; oversized delete_count stops at the end
+    let result = call_ok(
+        "splice",
+        vec![nums(&[1.0, 2.0]), num(10.0), num(5.0), nums(&[3.0])],
+    );
+    assert_eq!(result, nums(&[1.0, 2.0, 3.0]));
+    let result = call_ok(
+        "splice",
+        vec![nums(&[1.0, 2.0, 3.0]), num(-5.0), num(100.0), lst(vec![])],
+    );
+    assert_eq!(result, nums(&[]));
+}
+
+#[test]
+fn splice_invalid_counts() {
+    assert!(call(
+        "splice",
+        vec![nums(&[1.0]), num(0.0), num(-1.0), lst(vec![])]
+    )
+    .is_err());
+    assert!(call(
+        "splice",
+        vec![nums(&[1.0]), num(0.5), num(1.0), lst(vec![])]
+    )
+    .is_err());
+    assert!(call("splice", vec![nums(&[1.0]), num(0.0), num(1.0), num(2.0)]).is_err());
+}
+
 // ── list.map ──────────────────────────────────────────────────────────────────
 
 #[test]
@@ -634,6 +1060,28 @@ fn map_wrong_type_for_function() {
     assert!(call("map", vec![items, num(1.0)]).is_err());
 }
 
+#[test]
+fn map_rejects_callback_with_wrong_arity() {
+    let two_args = Value::Function(StdlibFn::new(|args| Ok(args[0].clone())).with_arity(2));
+    let err = call("map", vec![lst(vec![num(1.0)]), two_args]).unwrap_err();
+    match err {
+        StdlibError::RuntimeError(msg) => assert_eq!(
+            msg,
+            "list.map: callback takes 2 arguments, but list.map calls it with 1"
+        ),
+        other => panic!("expected RuntimeError, got {other:?}"),
+    }
+}
+
+#[test]
+fn map_accepts_callback_with_matching_arity() {
+    let f = Value::Function(StdlibFn::new(|args| Ok(args[0].clone())).with_arity(1));
+    assert_eq!(
+        call_ok("map", vec![lst(vec![num(1.0)]), f]),
+        lst(vec![num(1.0)])
+    );
+}
+
 // ── list.filter ───────────────────────────────────────────────────────────────
 
 #[test]
@@ -701,45 +1149,165 @@ fn reduce_string_concat() {
     assert_eq!(call_ok("reduce", vec![items, s(""), concat_fn]), s("abc"));
 }
 
-// ── list.find ─────────────────────────────────────────────────────────────────
-
 #[test]
-fn find_found() {
-    let items = lst(vec![num(1.0), num(2.0), num(3.0)]);
-    assert_eq!(call_ok("find", vec![items, gt(1.5)]), num(2.0));
+fn reduce_rejects_callback_with_wrong_arity() {
+    let one_arg = Value::Function(StdlibFn::new(|args| Ok(args[0].clone())).with_arity(1));
+    let err = call("reduce", vec![lst(vec![num(1.0)]), num(0.0), one_arg]).unwrap_err();
+    assert!(matches!(err, StdlibError::RuntimeError(msg) if msg.contains("takes 1 argument,")));
 }
 
-#[test]
-fn find_not_found() {
-    let items = lst(vec![num(1.0), num(2.0)]);
-    assert_eq!(call_ok("find", vec![items, gt(10.0)]), Value::Nil);
+// ── list.reduce_while ─────────────────────────────────────────────────────────
+
+/// Step: add item to acc; continue while the new sum is below `limit`.
+fn sum_until(limit: f64) -> Value {
+    pred_fn(move |args| {
+        let sum = args[0].as_number().unwrap() + args[1].as_number().unwrap();
+        Ok(Value::record_of(&[
+            ("acc", num(sum)),
+            ("continue", b(sum < limit)),
+        ]))
+    })
 }
 
 #[test]
-fn find_empty() {
-    assert_eq!(call_ok("find", vec![lst(vec![]), gt(0.0)]), Value::Nil);
+fn reduce_while_stops_midway() {
+    let items = lst(vec![num(1.0), num(2.0), num(3.0), num(4.0)]);
+    // 1, 3, 6 → stops once the sum reaches 5
+    assert_eq!(
+        call_ok("reduce_while", vec![items, num(0.0), sum_until(5.0)]),
+        num(6.0)
+    );
 }
 
-// ── list.find_index ───────────────────────────────────────────────────────────
-
 #[test]
-fn find_index_found() {
+fn reduce_while_runs_to_completion() {
     let items = lst(vec![num(1.0), num(2.0), num(3.0)]);
-    assert_eq!(call_ok("find_index", vec![items, gt(1.5)]), num(1.0));
+    assert_eq!(
+        call_ok("reduce_while", vec![items, num(0.0), sum_until(100.0)]),
+        num(6.0)
+    );
 }
 
 #[test]
-fn find_index_not_found() {
-    let items = lst(vec![num(1.0)]);
-    assert_eq!(call_ok("find_index", vec![items, gt(10.0)]), num(-1.0));
+fn reduce_while_empty_returns_initial() {
+    assert_eq!(
+        call_ok("reduce_while", vec![lst(vec![]), num(7.0), sum_until(5.0)]),
+        num(7.0)
+    );
 }
 
-// ── list.every ────────────────────────────────────────────────────────────────
-
 #[test]
-fn every_all_match() {
-    let items = lst(vec![num(2.0), num(4.0), num(6.0)]);
-    assert_eq!(call_ok("every", vec![items, is_even()]), b(true));
+fn reduce_while_does_not_call_step_after_stop() {
+    let step = pred_fn(|args| {
+        if args[1] == num(99.0) {
+            return Err(StdlibError::RuntimeError("called after stop".to_string()));
+        }
+        Ok(Value::record_of(&[
+            ("acc", args[1].clone()),
+            ("continue", b(false)),
+        ]))
+    });
+    let items = lst(vec![num(1.0), num(99.0)]);
+    assert_eq!(
+        call_ok("reduce_while", vec![items, num(0.0), step]),
+        num(1.0)
+    );
+}
+
+#[test]
+fn reduce_while_propagates_callback_error() {
+    let bad = pred_fn(|_| Err(StdlibError::RuntimeError("boom".to_string())));
+    let items = lst(vec![num(1.0)]);
+    assert!(call("reduce_while", vec![items, num(0.0), bad]).is_err());
+}
+
+#[test]
+fn reduce_while_validates_step_result() {
+    let items = lst(vec![num(1.0)]);
+    let not_record = pred_fn(|_| Ok(num(1.0)));
+    let err = call("reduce_while", vec![items.clone(), num(0.0), not_record]).unwrap_err();
+    assert!(matches!(err, StdlibError::RuntimeError(_)));
+    let missing_continue = pred_fn(|_| Ok(Value::record_of(&[("acc", num(1.0))])));
+    let err = call("reduce_while", vec![items, num(0.0), missing_continue]).unwrap_err();
+    assert!(matches!(err, StdlibError::RuntimeError(msg) if msg.contains("continue")));
+}
+
+// ── list.find ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn find_found() {
+    let items = lst(vec![num(1.0), num(2.0), num(3.0)]);
+    assert_eq!(call_ok("find", vec![items, gt(1.5)]), num(2.0));
+}
+
+#[test]
+fn find_not_found() {
+    let items = lst(vec![num(1.0), num(2.0)]);
+    assert_eq!(call_ok("find", vec![items, gt(10.0)]), Value::Nil);
+}
+
+#[test]
+fn find_empty() {
+    assert_eq!(call_ok("find", vec![lst(vec![]), gt(0.0)]), Value::Nil);
+}
+
+// ── list.find_index ───────────────────────────────────────────────────────────
+
+#[test]
+fn find_index_found() {
+    let items = lst(vec![num(1.0), num(2.0), num(3.0)]);
+    assert_eq!(call_ok("find_index", vec![items, gt(1.5)]), num(1.0));
+}
+
+#[test]
+fn find_index_not_found() {
+    let items = lst(vec![num(1.0)]);
+    assert_eq!(call_ok("find_index", vec![items, gt(10.0)]), num(-1.0));
+}
+
+// ── list.index_of_by ──────────────────────────────────────────────────────────
+
+fn field_of(key: &'static str) -> Value {
+    pred_fn(move |args| match &args[0] {
+        Value::Record { fields, .. } => Ok(fields.get(key).cloned().unwrap_or(Value::Nil)),
+        other => Err(StdlibError::RuntimeError(format!("not a record: {other}"))),
+    })
+}
+
+#[test]
+fn index_of_by_record_id() {
+    let items = lst(vec![
+        Value::record_of(&[("id", num(7.0)), ("name", s("a"))]),
+        Value::record_of(&[("id", num(9.0)), ("name", s("b"))]),
+        Value::record_of(&[("id", num(9.0)), ("name", s("c"))]),
+    ]);
+    assert_eq!(
+        call_ok("index_of_by", vec![items, num(9.0), field_of("id")]),
+        num(1.0)
+    );
+}
+
+#[test]
+fn index_of_by_no_match() {
+    let items = lst(vec![Value::record_of(&[("id", num(1.0))])]);
+    assert_eq!(
+        call_ok("index_of_by", vec![items, num(2.0), field_of("id")]),
+        num(-1.0)
+    );
+}
+
+#[test]
+fn index_of_by_propagates_callback_error() {
+    let items = lst(vec![num(1.0)]);
+    assert!(call("index_of_by", vec![items, num(1.0), field_of("id")]).is_err());
+}
+
+// ── list.every ────────────────────────────────────────────────────────────────
+
+#[test]
+fn every_all_match() {
+    let items = lst(vec![num(2.0), num(4.0), num(6.0)]);
+    assert_eq!(call_ok("every", vec![items, is_even()]), b(true));
 }
 
 #[test]
@@ -827,6 +1395,114 @@ fn sort_comparator_error() {
     assert!(call("sort", vec![items, bad_cmp]).is_err());
 }
 
+#[test]
+fn sort_without_comparator_uses_natural_order() {
+    let items = lst(vec![s("b"), num(2.0), Value::Nil, num(-1.0), s("a")]);
+    assert_eq!(
+        call_ok("sort", vec![items]),
+        lst(vec![Value::Nil, num(-1.0), num(2.0), s("a"), s("b")])
+    );
+}
+
+#[test]
+fn sort_wrong_arg_count() {
+    assert!(matches!(
+        call("sort", vec![]),
+        Err(StdlibError::WrongArgCount {
+            expected: 1,
+            got: 0,
+            ..
+        })
+    ));
+    assert!(matches!(
+        call("sort", vec![lst(vec![]), cmp_asc(), cmp_asc()]),
+        Err(StdlibError::WrongArgCount {
+            expected: 2,
+            got: 3,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn sort_rejects_comparator_with_wrong_arity() {
+    let unary = Value::Function(StdlibFn::new(|_| Ok(num(0.0))).with_arity(1));
+    let err = call("sort", vec![lst(vec![num(2.0), num(1.0)]), unary]).unwrap_err();
+    assert!(matches!(err, StdlibError::RuntimeError(_)));
+}
+
+// ── list.is_sorted ────────────────────────────────────────────────────────────
+
+#[test]
+fn is_sorted_ascending() {
+    let items = lst(vec![num(1.0), num(2.0), num(3.0)]);
+    assert_eq!(
+        call_ok("is_sorted", vec![items.clone(), cmp_asc()]),
+        b(true)
+    );
+    assert_eq!(call_ok("is_sorted", vec![items, cmp_desc()]), b(false));
+}
+
+#[test]
+fn is_sorted_unsorted() {
+    let items = lst(vec![num(1.0), num(3.0), num(2.0)]);
+    assert_eq!(call_ok("is_sorted", vec![items, cmp_asc()]), b(false));
+}
+
+#[test]
+fn is_sorted_equal_elements() {
+    let items = lst(vec![num(2.0), num(2.0), num(2.0)]);
+    assert_eq!(call_ok("is_sorted", vec![items, cmp_asc()]), b(true));
+}
+
+#[test]
+fn is_sorted_empty_and_single() {
+    assert_eq!(call_ok("is_sorted", vec![lst(vec![]), cmp_asc()]), b(true));
+    assert_eq!(
+        call_ok("is_sorted", vec![lst(vec![num(5.0)]), cmp_asc()]),
+        b(true)
+    );
+    assert_eq!(call_ok("is_sorted", vec![lst(vec![])]), b(true));
+}
+
+#[test]
+fn is_sorted_without_comparator_uses_natural_order() {
+    let sorted = lst(vec![Value::Nil, num(-1.0), num(2.0), s("a")]);
+    assert_eq!(call_ok("is_sorted", vec![sorted]), b(true));
+    let unsorted = lst(vec![s("b"), s("a")]);
+    assert_eq!(call_ok("is_sorted", vec![unsorted]), b(false));
+}
+
+#[test]
+fn is_sorted_comparator_error() {
+    let bad_cmp = pred_fn(|_| Err(StdlibError::RuntimeError("boom".to_string())));
+    let items = lst(vec![num(1.0), num(2.0)]);
+    assert!(call("is_sorted", vec![items, bad_cmp]).is_err());
+    let non_number = pred_fn(|_| Ok(b(true)));
+    let items = lst(vec![num(1.0), num(2.0)]);
+    assert!(call("is_sorted", vec![items, non_number]).is_err());
+}
+
+#[test]
+fn is_sorted_wrong_arg_count() {
+    assert!(matches!(
+        call("is_sorted", vec![]),
+        Err(StdlibError::WrongArgCount {
+            expected: 1,
+            got: 0,
+            ..
+        })
+    ));
+    assert!(matches!(
+        call("is_sorted", vec![lst(vec![]), cmp_asc(), cmp_asc()]),
+        Err(StdlibError::WrongArgCount {
+            expected: 2,
+            got: 3,
+            ..
+        })
+    ));
+}
+
 // ── list.count ────────────────────────────────────────────────────────────────
 
 #[test]
@@ -850,6 +1526,64 @@ fn count_empty() {
 // Query
 // ═══════════════════════════════════════════════════════════════════════════════
 
+// ── list.group_consecutive ────────────────────────────────────────────────────
+
+fn identity() -> Value {
+    pred_fn(|args| Ok(args[0].clone()))
+}
+
+fn run(key: Value, items: Vec<Value>) -> Value {
+    Value::record_of(&[("key", key), ("items", lst(items))])
+}
+
+#[test]
+fn group_consecutive_runs() {
+    let items = lst(vec![
+        num(1.0),
+        num(1.0),
+        num(2.0),
+        num(2.0),
+        num(2.0),
+        num(1.0),
+    ]);
+    assert_eq!(
+        call_ok("group_consecutive", vec![items, identity()]),
+        lst(vec![
+            run(num(1.0), vec![num(1.0), num(1.0)]),
+            run(num(2.0), vec![num(2.0), num(2.0), num(2.0)]),
+            run(num(1.0), vec![num(1.0)]),
+        ])
+    );
+}
+
+#[test]
+fn group_consecutive_uses_key_fn() {
+    let parity = pred_fn(|args| Ok(b(args[0].as_number().unwrap() % 2.0 == 0.0)));
+    let items = lst(vec![num(2.0), num(4.0), num(1.0), num(3.0), num(6.0)]);
+    assert_eq!(
+        call_ok("group_consecutive", vec![items, parity]),
+        lst(vec![
+            run(b(true), vec![num(2.0), num(4.0)]),
+            run(b(false), vec![num(1.0), num(3.0)]),
+            run(b(true), vec![num(6.0)]),
+        ])
+    );
+}
+
+#[test]
+fn group_consecutive_empty() {
+    assert_eq!(
+        call_ok("group_consecutive", vec![lst(vec![]), identity()]),
+        lst(vec![])
+    );
+}
+
+#[test]
+fn group_consecutive_propagates_callback_error() {
+    let bad = pred_fn(|_| Err(StdlibError::RuntimeError("boom".to_string())));
+    assert!(call("group_consecutive", vec![lst(vec![num(1.0)]), bad]).is_err());
+}
+
 // ── list.contains ─────────────────────────────────────────────────────────────
 
 #[test]
@@ -924,6 +1658,202 @@ fn zip_wrong_type() {
     assert!(call("zip", vec![lst(vec![]), num(1.0)]).is_err());
 }
 
+// ── list.zip_longest ──────────────────────────────────────────────────────────
+
+#[test]
+fn zip_longest_equal_lengths() {
+    let result = call_ok(
+        "zip_longest",
+        vec![nums(&[1.0, 2.0]), nums(&[3.0, 4.0]), Value::Nil],
+    );
+    assert_eq!(
+        result,
+        lst(vec![pair(num(1.0), num(3.0)), pair(num(2.0), num(4.0))])
+    );
+}
+
+#[test]
+fn zip_longest_longer_first() {
+    let result = call_ok(
+        "zip_longest",
+        vec![nums(&[1.0, 2.0, 3.0]), nums(&[4.0]), num(0.0)],
+    );
+    assert_eq!(
+        result,
+        lst(vec![
+            pair(num(1.0), num(4.0)),
+            pair(num(2.0), num(0.0)),
+            pair(num(3.0), num(0.0)),
+        ])
+    );
+}
+
+#[test]
+fn zip_longest_longer_second() {
+    let result = call_ok("zip_longest", vec![nums(&[1.0]), nums(&[4.0, 5.0]), s("-")]);
+    assert_eq!(
+        result,
+        lst(vec![pair(num(1.0), num(4.0)), pair(s("-"), num(5.0))])
+    );
+}
+
+#[test]
+fn zip_longest_fill_on_right() {
+    let result = call_ok(
+        "zip_longest",
+        vec![nums(&[1.0, 2.0]), lst(vec![]), s("fill")],
+    );
+    assert_eq!(
+        result,
+        lst(vec![pair(num(1.0), s("fill")), pair(num(2.0), s("fill"))])
+    );
+}
+
+// ── list.zip_named ────────────────────────────────────────────────────────────
+
+fn point(x: f64, y: f64) -> Value {
+    Value::record_of(&[("x", num(x)), ("y", num(y))])
+}
+
+#[test]
+fn zip_named_builds_records() {
+    let result = call_ok(
+        "zip_named",
+        vec![nums(&[1.0, 2.0]), nums(&[3.0, 4.0]), s("x"), s("y")],
+    );
+    assert_eq!(result, lst(vec![point(1.0, 3.0), point(2.0, 4.0)]));
+}
+
+#[test]
+fn zip_named_stops_at_shorter() {
+    let result = call_ok(
+        "zip_named",
+        vec![nums(&[1.0, 2.0, 3.0]), nums(&[4.0]), s("x"), s("y")],
+    );
+    assert_eq!(result, lst(vec![point(1.0, 4.0)]));
+    let result = call_ok("zip_named", vec![lst(vec![]), nums(&[4.0]), s("x"), s("y")]);
+    assert_eq!(result, lst(vec![]));
+}
+
+#[test]
+fn zip_named_rejects_bad_names() {
+    let err = call(
+        "zip_named",
+        vec![nums(&[1.0]), nums(&[2.0]), s("x"), s("x")],
+    )
+    .unwrap_err();
+    assert!(matches!(err, StdlibError::RuntimeError(msg) if msg.contains("must differ")));
+    let err = call(
+        "zip_named",
+        vec![nums(&[1.0]), nums(&[2.0]), s("x"), num(1.0)],
+    )
+    .unwrap_err();
+    assert!(matches!(err, StdlibError::TypeMismatch { .. }));
+}
+
+// ── list.zip3 ─────────────────────────────────────────────────────────────────
+
+fn triple(first: Value, second: Value, third: Value) -> Value {
+    Value::record_of(&[("first", first), ("second", second), ("third", third)])
+}
+
+#[test]
+fn zip3_equal_lengths() {
+    let a = lst(vec![num(1.0), num(2.0)]);
+    let bs = lst(vec![s("a"), s("b")]);
+    let c = lst(vec![b(true), b(false)]);
+    assert_eq!(
+        call_ok("zip3", vec![a, bs, c]),
+        lst(vec![
+            triple(num(1.0), s("a"), b(true)),
+            triple(num(2.0), s("b"), b(false)),
+        ])
+    );
+}
+
+#[test]
+fn zip3_stops_at_shortest() {
+    let a = lst(vec![num(1.0), num(2.0), num(3.0)]);
+    let bs = lst(vec![s("a")]);
+    let c = lst(vec![b(true), b(false)]);
+    assert_eq!(
+        call_ok("zip3", vec![a, bs, c]),
+        lst(vec![triple(num(1.0), s("a"), b(true))])
+    );
+}
+
+#[test]
+fn zip3_empty_input() {
+    let a = lst(vec![num(1.0)]);
+    assert_eq!(
+        call_ok("zip3", vec![a.clone(), lst(vec![]), a]),
+        lst(vec![])
+    );
+}
+
+#[test]
+fn zip3_non_list_argument() {
+    let a = lst(vec![num(1.0)]);
+    let err = call("zip3", vec![a.clone(), a, num(1.0)]).unwrap_err();
+    assert!(matches!(err, StdlibError::TypeMismatch { position: 3, .. }));
+}
+
+// ── list.unzip ────────────────────────────────────────────────────────────────
+
+#[test]
+fn unzip_round_trip_with_zip() {
+    let a = lst(vec![num(1.0), num(2.0), num(3.0)]);
+    let b_list = lst(vec![s("a"), s("b"), s("c")]);
+    let zipped = call_ok("zip", vec![a.clone(), b_list.clone()]);
+    let result = call_ok("unzip", vec![zipped]);
+    assert_eq!(
+        result,
+        Value::record_of(&[("firsts", a), ("seconds", b_list)])
+    );
+}
+
+#[test]
+fn unzip_empty() {
+    assert_eq!(
+        call_ok("unzip", vec![lst(vec![])]),
+        Value::record_of(&[("firsts", lst(vec![])), ("seconds", lst(vec![]))])
+    );
+}
+
+#[test]
+fn unzip_malformed_element() {
+    let missing_second = Value::record_of(&[("first", num(1.0))]);
+    let err = call("unzip", vec![lst(vec![missing_second])]).unwrap_err();
+    assert!(err.to_string().contains("element 0"), "{err}");
+    assert!(call("unzip", vec![lst(vec![num(1.0)])]).is_err());
+}
+
+// ── list.pairwise ─────────────────────────────────────────────────────────────
+
+fn pair(first: Value, second: Value) -> Value {
+    Value::record_of(&[("first", first), ("second", second)])
+}
+
+#[test]
+fn pairwise_three_elements() {
+    let items = lst(vec![num(1.0), num(4.0), num(9.0)]);
+    assert_eq!(
+        call_ok("pairwise", vec![items]),
+        lst(vec![pair(num(1.0), num(4.0)), pair(num(4.0), num(9.0))])
+    );
+}
+
+#[test]
+fn pairwise_short_lists_empty() {
+    assert_eq!(call_ok("pairwise", vec![lst(vec![])]), lst(vec![]));
+    assert_eq!(call_ok("pairwise", vec![lst(vec![num(1.0)])]), lst(vec![]));
+}
+
+#[test]
+fn pairwise_wrong_type() {
+    assert!(call("pairwise", vec![s("abc")]).is_err());
+}
+
 // ── list.take ─────────────────────────────────────────────────────────────────
 
 #[test]
@@ -1024,6 +1954,521 @@ fn drop_wrong_type() {
     assert!(call("drop", vec![num(1.0), num(1.0)]).is_err());
 }
 
+// ── list.sample ───────────────────────────────────────────────────────────────
+
+#[test]
+fn sample_zero_is_empty() {
+    assert_eq!(
+        call_ok("sample", vec![one_to_ten(), num(0.0), num(1.0)]),
+        lst(vec![])
+    );
+}
+
+#[test]
+fn sample_subset_without_replacement() {
+    let picked = call_ok("sample", vec![one_to_ten(), num(4.0), num(3.0)]);
+    let picked = picked.as_list().unwrap();
+    assert_eq!(picked.len(), 4);
+    let unique = call_ok("unique", vec![lst(picked.to_vec())]);
+    assert_eq!(unique.as_list().unwrap().len(), 4);
+    for item in picked {
+        assert!(one_to_ten().as_list().unwrap().contains(item));
+    }
+}
+
+#[test]
+fn sample_n_at_least_length_is_permutation() {
+    let picked = call_ok("sample", vec![one_to_ten(), num(25.0), num(9.0)]);
+    assert_eq!(picked.as_list().unwrap().len(), 10);
+    assert_eq!(call_ok("sort", vec![picked, cmp_asc()]), one_to_ten());
+}
+
+#[test]
+fn sample_reproducible() {
+    let first = call_ok("sample", vec![one_to_ten(), num(3.0), num(99.0)]);
+    for _ in 0..10 {
+        assert_eq!(
+            call_ok("sample", vec![one_to_ten(), num(3.0), num(99.0)]),
+            first
+        );
+    }
+}
+
+#[test]
+fn sample_invalid_count() {
+    assert!(call("sample", vec![one_to_ten(), num(-1.0), num(0.0)]).is_err());
+    assert!(call("sample", vec![one_to_ten(), num(1.5), num(0.0)]).is_err());
+    assert!(call("sample", vec![one_to_ten(), num(1.0)]).is_err());
+}
+
+// ── list.frequencies ──────────────────────────────────────────────────────────
+
+fn freq(value: Value, count: f64) -> Value {
+    Value::record_of(&[("value", value), ("count", num(count))])
+}
+
+#[test]
+fn frequencies_mixed_types() {
+    let items = lst(vec![num(1.0), s("1"), num(1.0), b(true), s("1"), num(1.0)]);
+    assert_eq!(
+        call_ok("frequencies", vec![items]),
+        lst(vec![
+            freq(num(1.0), 3.0),
+            freq(s("1"), 2.0),
+            freq(b(true), 1.0)
+        ])
+    );
+}
+
+#[test]
+fn frequencies_all_unique() {
+    let items = lst(vec![s("a"), s("b"), s("c")]);
+    assert_eq!(
+        call_ok("frequencies", vec![items]),
+        lst(vec![
+            freq(s("a"), 1.0),
+            freq(s("b"), 1.0),
+            freq(s("c"), 1.0)
+        ])
+    );
+}
+
+#[test]
+fn frequencies_all_equal() {
+    let items = lst(vec![Value::Nil, Value::Nil, Value::Nil]);
+    assert_eq!(
+        call_ok("frequencies", vec![items]),
+        lst(vec![freq(Value::Nil, 3.0)])
+    );
+}
+
+#[test]
+fn frequencies_empty() {
+    assert_eq!(call_ok("frequencies", vec![lst(vec![])]), lst(vec![]));
+}
+
+// ── list.min / list.max ───────────────────────────────────────────────────────
+
+#[test]
+fn min_max_numbers() {
+    let items = lst(vec![num(3.0), num(-1.0), num(7.5), num(0.0)]);
+    assert_eq!(call_ok("min", vec![items.clone()]), num(-1.0));
+    assert_eq!(call_ok("max", vec![items]), num(7.5));
+}
+
+#[test]
+fn min_max_strings() {
+    let items = lst(vec![s("pear"), s("apple"), s("zucchini")]);
+    assert_eq!(call_ok("min", vec![items.clone()]), s("apple"));
+    assert_eq!(call_ok("max", vec![items]), s("zucchini"));
+}
+
+#[test]
+fn min_max_empty_is_nil() {
+    assert_eq!(call_ok("min", vec![lst(vec![])]), Value::Nil);
+    assert_eq!(call_ok("max", vec![lst(vec![])]), Value::Nil);
+}
+
+#[test]
+fn min_max_mixed_types_error() {
+    let items = lst(vec![num(1.0), s("a")]);
+    match call("min", vec![items]).unwrap_err() {
+        StdlibError::RuntimeError(msg) => {
+            assert_eq!(msg, "list.min: cannot compare number with string")
+        }
+        other => panic!("expected RuntimeError, got {other:?}"),
+    }
+    assert!(call("max", vec![lst(vec![lst(vec![]), lst(vec![])])]).is_err());
+}
+
+// ── list.all_equal / list.has_duplicates ──────────────────────────────────────
+
+#[test]
+fn all_equal_uniform() {
+    assert_eq!(call_ok("all_equal", vec![nums(&[2.0, 2.0, 2.0])]), b(true));
+    let records = lst(vec![
+        Value::record_of(&[("a", num(1.0))]),
+        Value::record_of(&[("a", num(1.0))]),
+    ]);
+    assert_eq!(call_ok("all_equal", vec![records]), b(true));
+}
+
+#[test]
+fn all_equal_one_odd_element() {
+    assert_eq!(call_ok("all_equal", vec![nums(&[2.0, 2.0, 3.0])]), b(false));
+    assert_eq!(
+        call_ok("all_equal", vec![lst(vec![num(1.0), s("1")])]),
+        b(false)
+    );
+}
+
+#[test]
+fn all_equal_empty_and_single() {
+    assert_eq!(call_ok("all_equal", vec![lst(vec![])]), b(true));
+    assert_eq!(call_ok("all_equal", vec![nums(&[5.0])]), b(true));
+}
+
+#[test]
+fn has_duplicates_cases() {
+    assert_eq!(
+        call_ok("has_duplicates", vec![nums(&[1.0, 2.0, 3.0, 2.0])]),
+        b(true)
+    );
+    assert_eq!(
+        call_ok("has_duplicates", vec![nums(&[1.0, 2.0, 3.0])]),
+        b(false)
+    );
+    assert_eq!(
+        call_ok("has_duplicates", vec![lst(vec![s("a"), num(1.0), s("a")])]),
+        b(true)
+    );
+}
+
+#[test]
+fn has_duplicates_empty_and_single() {
+    assert_eq!(call_ok("has_duplicates", vec![lst(vec![])]), b(false));
+    assert_eq!(call_ok("has_duplicates", vec![nums(&[1.0])]), b(false));
+}
+
+// ── list.merge_records ────────────────────────────────────────────────────────
+
+#[test]
+fn merge_records_last_wins() {
+    let items = lst(vec![
+        Value::record_of(&[("theme", s("light")), ("size", num(12.0))]),
+        Value::record_of(&[("theme", s("dark"))]),
+    ]);
+    assert_eq!(
+        call_ok("merge_records", vec![items]),
+        Value::record_of(&[("theme", s("dark")), ("size", num(12.0))])
+    );
+}
+
+#[test]
+fn merge_records_disjoint_keys() {
+    let items = lst(vec![
+        Value::record_of(&[("a", num(1.0))]),
+        Value::record_of(&[("b", num(2.0))]),
+    ]);
+    assert_eq!(
+        call_ok("merge_records", vec![items]),
+        Value::record_of(&[("a", num(1.0)), ("b", num(2.0))])
+    );
+}
+
+#[test]
+fn merge_records_empty() {
+    assert_eq!(
+        call_ok("merge_records", vec![lst(vec![])]),
+        Value::record_of(&[])
+    );
+}
+
+#[test]
+fn merge_records_non_record_reports_index() {
+    let items = lst(vec![Value::record_of(&[("a", num(1.0))]), num(2.0)]);
+    let err = call("merge_records", vec![items]).unwrap_err();
+    assert!(matches!(err, StdlibError::RuntimeError(msg) if msg.contains("element 1")));
+}
+
+// ── list.accumulate ───────────────────────────────────────────────────────────
+
+#[test]
+fn accumulate_prefix_sums() {
+    assert_eq!(
+        call_ok("accumulate", vec![nums(&[1.0, 2.0, 3.0])]),
+        nums(&[1.0, 3.0, 6.0])
+    );
+}
+
+#[test]
+fn accumulate_negatives() {
+    assert_eq!(
+        call_ok("accumulate", vec![nums(&[5.0, -2.0, -4.0, 1.5])]),
+        nums(&[5.0, 3.0, -1.0, 0.5])
+    );
+}
+
+#[test]
+fn accumulate_single_and_empty() {
+    assert_eq!(call_ok("accumulate", vec![nums(&[7.0])]), nums(&[7.0]));
+    assert_eq!(call_ok("accumulate", vec![lst(vec![])]), lst(vec![]));
+}
+
+#[test]
+fn accumulate_rejects_non_numbers_and_overflow() {
+    let err = call("accumulate", vec![lst(vec![num(1.0), s("2")])]).unwrap_err();
+    assert!(matches!(err, StdlibError::RuntimeError(msg) if msg.contains("element 1")));
+    let huge = nums(&[f64::MAX, f64::MAX]);
+    assert!(matches!(
+        call("accumulate", vec![huge]),
+        Err(StdlibError::RuntimeError(_))
+    ));
+}
+
+// ── list.sum_by / list.average_by ─────────────────────────────────────────────
+
+fn price_of() -> Value {
+    pred_fn(|args| Ok(args[0].as_record().unwrap()["price"].clone()))
+}
+
+fn priced(prices: &[f64]) -> Value {
+    lst(prices
+        .iter()
+        .map(|p| Value::record_of(&[("price", num(*p))]))
+        .collect())
+}
+
+#[test]
+fn sum_by_record_field() {
+    assert_eq!(
+        call_ok("sum_by", vec![priced(&[2.5, 4.0, 3.5]), price_of()]),
+        num(10.0)
+    );
+    assert_eq!(call_ok("sum_by", vec![lst(vec![]), price_of()]), num(0.0));
+}
+
+#[test]
+fn average_by_record_field() {
+    assert_eq!(
+        call_ok("average_by", vec![priced(&[2.0, 4.0, 9.0]), price_of()]),
+        num(5.0)
+    );
+}
+
+#[test]
+fn average_by_empty_traps() {
+    let err = call("average_by", vec![lst(vec![]), price_of()]).unwrap_err();
+    assert!(matches!(err, StdlibError::RuntimeError(msg) if msg.contains("empty")));
+}
+
+#[test]
+fn sum_by_rejects_non_number_keys() {
+    let err = call("sum_by", vec![nums(&[1.0]), to_string_fn()]).unwrap_err();
+    assert!(matches!(err, StdlibError::RuntimeError(msg) if msg.contains("number")));
+}
+
+#[test]
+fn sum_by_guards_overflow_and_propagates_errors() {
+    let items = priced(&[f64::MAX, f64::MAX]);
+    assert!(call("sum_by", vec![items, price_of()]).is_err());
+    let bad = pred_fn(|_| Err(StdlibError::RuntimeError("boom".to_string())));
+    assert!(call("average_by", vec![nums(&[1.0]), bad]).is_err());
+}
+
+// ── list.to_record ────────────────────────────────────────────────────────────
+
+#[test]
+fn to_record_matching_lengths() {
+    let keys = lst(vec![s("name"), s("age")]);
+    let values = lst(vec![s("Ada"), num(36.0)]);
+    assert_eq!(
+        call_ok("to_record", vec![keys, values]),
+        Value::record_of(&[("name", s("Ada")), ("age", num(36.0))]).ok()
+    );
+}
+
+#[test]
+fn to_record_length_mismatch_is_err() {
+    let result = call_ok("to_record", vec![lst(vec![s("a"), s("b")]), nums(&[1.0])]);
+    assert_eq!(result, s("keys and values differ in length (2 vs 1)").err());
+}
+
+#[test]
+fn to_record_non_string_key_is_err() {
+    let result = call_ok(
+        "to_record",
+        vec![lst(vec![s("a"), num(2.0)]), nums(&[1.0, 2.0])],
+    );
+    assert_eq!(result, s("key 1 must be a string, got number").err());
+}
+
+#[test]
+fn to_record_duplicate_keys_last_wins() {
+    let keys = lst(vec![s("k"), s("other"), s("k")]);
+    assert_eq!(
+        call_ok("to_record", vec![keys, nums(&[1.0, 2.0, 3.0])]),
+        Value::record_of(&[("k", num(3.0)), ("other", num(2.0))]).ok()
+    );
+}
+
+// ── list.positions ────────────────────────────────────────────────────────────
+
+#[test]
+fn positions_multiple_matches() {
+    assert_eq!(
+        call_ok(
+            "positions",
+            vec![nums(&[1.0, 2.0, 3.0, 4.0, 6.0]), is_even()]
+        ),
+        nums(&[1.0, 3.0, 4.0])
+    );
+}
+
+#[test]
+fn positions_no_matches() {
+    assert_eq!(
+        call_ok("positions", vec![nums(&[1.0, 3.0, 5.0]), is_even()]),
+        lst(vec![])
+    );
+}
+
+#[test]
+fn positions_all_match() {
+    assert_eq!(
+        call_ok("positions", vec![nums(&[2.0, 4.0, 6.0]), is_even()]),
+        nums(&[0.0, 1.0, 2.0])
+    );
+}
+
+#[test]
+fn positions_propagates_callback_error() {
+    let bad = pred_fn(|_| Err(StdlibError::RuntimeError("boom".to_string())));
+    assert!(call("positions", vec![nums(&[1.0]), bad]).is_err());
+}
+
+#[test]
+fn positions_rejects_callback_with_wrong_arity() {
+    let two_args = Value::Function(StdlibFn::new(|_| Ok(b(true))).with_arity(2));
+    let err = call("positions", vec![nums(&[1.0]), two_args]).unwrap_err();
+    assert!(matches!(err, StdlibError::RuntimeError(msg) if msg.contains("takes 2 arguments,")));
+}
+
+// ── list.collect_results ──────────────────────────────────────────────────────
+
+#[test]
+fn collect_results_all_ok() {
+    let items = lst(vec![num(1.0).ok(), num(2.0).ok(), num(3.0).ok()]);
+    assert_eq!(
+        call_ok("collect_results", vec![items]),
+        nums(&[1.0, 2.0, 3.0]).ok()
+    );
+}
+
+#[test]
+fn collect_results_returns_first_err() {
+    let items = lst(vec![
+        num(1.0).ok(),
+        s("bad").err(),
+        num(3.0).ok(),
+        s("worse").err(),
+    ]);
+    assert_eq!(call_ok("collect_results", vec![items]), s("bad").err());
+}
+
+#[test]
+fn collect_results_short_circuits() {
+    // Nothing after the first Err is inspected, not even its type
+    let items = lst(vec![s("bad").err(), num(2.0)]);
+    assert_eq!(call_ok("collect_results", vec![items]), s("bad").err());
+}
+
+#[test]
+fn collect_results_empty_is_ok() {
+    assert_eq!(
+        call_ok("collect_results", vec![lst(vec![])]),
+        lst(vec![]).ok()
+    );
+}
+
+#[test]
+fn collect_results_non_result_element() {
+    let err = call("collect_results", vec![lst(vec![num(1.0).ok(), num(2.0)])]).unwrap_err();
+    assert!(matches!(err, StdlibError::RuntimeError(msg) if msg.contains("element 1")));
+}
+
+// ── list.partition_results ────────────────────────────────────────────────────
+
+fn oks_errs(oks: Vec<Value>, errs: Vec<Value>) -> Value {
+    Value::record_of(&[("oks", lst(oks)), ("errs", lst(errs))])
+}
+
+#[test]
+fn partition_results_mixed() {
+    let items = lst(vec![
+        num(1.0).ok(),
+        s("a").err(),
+        num(2.0).ok(),
+        s("b").err(),
+    ]);
+    assert_eq!(
+        call_ok("partition_results", vec![items]),
+        oks_errs(vec![num(1.0), num(2.0)], vec![s("a"), s("b")])
+    );
+}
+
+#[test]
+fn partition_results_all_ok() {
+    let items = lst(vec![num(1.0).ok(), num(2.0).ok()]);
+    assert_eq!(
+        call_ok("partition_results", vec![items]),
+        oks_errs(vec![num(1.0), num(2.0)], vec![])
+    );
+}
+
+#[test]
+fn partition_results_all_err() {
+    let items = lst(vec![s("x").err(), s("y").err()]);
+    assert_eq!(
+        call_ok("partition_results", vec![items]),
+        oks_errs(vec![], vec![s("x"), s("y")])
+    );
+}
+
+#[test]
+fn partition_results_non_result_element() {
+    let err = call(
+        "partition_results",
+        vec![lst(vec![num(1.0).ok(), s("plain")])],
+    )
+    .unwrap_err();
+    assert!(matches!(err, StdlibError::RuntimeError(msg) if msg.contains("element 1")));
+}
+
+// ── list.update_where ─────────────────────────────────────────────────────────
+
+#[test]
+fn update_where_only_matching() {
+    assert_eq!(
+        call_ok(
+            "update_where",
+            vec![nums(&[1.0, 2.0, 3.0, 4.0]), is_even(), double()]
+        ),
+        nums(&[1.0, 4.0, 3.0, 8.0])
+    );
+}
+
+#[test]
+fn update_where_none_matching_is_unchanged() {
+    let items = nums(&[1.0, 3.0, 5.0]);
+    assert_eq!(
+        call_ok("update_where", vec![items.clone(), is_even(), double()]),
+        items
+    );
+}
+
+#[test]
+fn update_where_all_matching_equals_map() {
+    let items = nums(&[2.0, 4.0, 6.0]);
+    assert_eq!(
+        call_ok("update_where", vec![items.clone(), is_even(), double()]),
+        call_ok("map", vec![items, double()])
+    );
+}
+
+#[test]
+fn update_where_propagates_callback_errors() {
+    let failing = pred_fn(|_| Err(StdlibError::RuntimeError("boom".to_string())));
+    let err = call(
+        "update_where",
+        vec![nums(&[1.0, 2.0]), is_even(), failing.clone()],
+    )
+    .unwrap_err();
+    assert!(matches!(err, StdlibError::RuntimeError(msg) if msg == "boom"));
+    let err = call("update_where", vec![nums(&[1.0]), failing, double()]).unwrap_err();
+    assert!(matches!(err, StdlibError::RuntimeError(msg) if msg == "boom"));
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // Module trait
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -1036,26 +2481,31 @@ fn module_name() {
 #[test]
 fn has_all_functions() {
     let m = list();
-    // 31 spec functions + 5 extensions (insert, update, find_index, zip, flatten)
-    // + backward-compat alias (some → any)
+    // Every function in the module docs, plus the `set` (→ update) and
+    // `some` (→ any) aliases
     let functions = [
         // Construction
-        "empty", "of", "repeat", "range",
+        "empty", "of", "repeat", "range", "repeat_with", "range_map_filter",
         // Access
         "length", "get", "first", "last", "index_of",
         // Modification
         "append", "prepend", "insert", "remove", "update", "set",
-        "slice", "concat", "reverse", "flatten", "unique",
+        "slice", "concat", "reverse", "flatten", "unique", "dedup_consecutive", "shuffle",
+        "splice", "concat_all", "interleave", "without", "replace", "swap",
         // Higher-order
-        "map", "filter", "reduce", "find", "find_index",
-        "every", "any", "some", "sort", "count",
+        "map", "filter", "reduce", "find", "find_index", "index_of_by",
+        "every", "any", "some", "sort", "count", "is_sorted", "reduce_while",
+        "group_consecutive", "sum_by", "average_by", "positions", "update_where",
         // Query
-        "contains", "zip", "take", "drop",
+        "contains", "zip", "unzip", "pairwise", "take", "drop", "sample", "frequencies",
+        "zip3", "min", "max", "all_equal", "has_duplicates", "merge_records",
+        "zip_longest", "accumulate", "to_record", "zip_named", "collect_results",
+        "partition_results",
     ];
     for f in &functions {
         assert!(m.has_function(f), "missing function: {f}");
     }
-    assert_eq!(functions.len(), 34); // 32 unique + set alias + some alias
+    assert_eq!(functions.len(), 68); // 66 unique + set alias + some alias
 }
 
 #[test]
@@ -1,5 +1,7 @@
 //! Integration tests for `pepl-stdlib` Phase 2: math module.
 
+#![allow(clippy::approx_constant)] // 3.14 etc. are arbitrary decimals, not PI
+
 use pepl_stdlib::modules::math::MathModule;
 use pepl_stdlib::{StdlibError, StdlibModule, Value};
 
@@ -18,7 +20,7 @@ fn call(func: &str, args: Vec<Value>) -> Result<Value, StdlibError> {
 }
 
 fn call_ok(func: &str, args: Vec<Value>) -> Value {
-    call(func, args).expect(&format!("math.{func} should succeed"))
+    call(func, args).unwrap_or_else(|e| panic!("math.{func} should succeed: {e}"))
 }
 
 fn expect_num(func: &str, args: Vec<Value>) -> f64 {
@@ -41,7 +43,32 @@ fn test_module_name() {
 fn test_has_function_known() {
     let m = math();
     for f in &[
-        "abs", "min", "max", "floor", "ceil", "round", "round_to", "pow", "clamp", "sqrt", "PI",
+        "abs",
+        "min",
+        "max",
+        "floor",
+        "ceil",
+        "round",
+        "round_to",
+        "pow",
+        "clamp",
+        "sqrt",
+        "map_range",
+        "factorial",
+        "combinations",
+        "is_close",
+        "clamp01",
+        "saturate",
+        "round_half_even",
+        "degrees",
+        "radians",
+        "normalize",
+        "clamp_magnitude",
+        "wrap",
+        "snap_integer",
+        "floor_to",
+        "ceil_to",
+        "PI",
         "E",
     ] {
         assert!(m.has_function(f), "math should have function {f}");
@@ -268,6 +295,47 @@ fn test_round_zero() {
     assert_eq!(expect_num("round", vec![num(0.0)]), 0.0);
 }
 
+// ══════════════════════════════════════════════════════════════════════════════
+// math.round_half_even — ties go to the even neighbour
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_round_half_even_ties() {
+    assert_eq!(expect_num("round_half_even", vec![num(0.5)]), 0.0);
+    assert_eq!(expect_num("round_half_even", vec![num(1.5)]), 2.0);
+    assert_eq!(expect_num("round_half_even", vec![num(2.5)]), 2.0);
+    assert_eq!(expect_num("round_half_even", vec![num(3.5)]), 4.0);
+}
+
+#[test]
+fn test_round_half_even_negative_ties() {
+    let r = expect_num("round_half_even", vec![num(-0.5)]);
+    assert_eq!(r, 0.0);
+    assert!(r.is_sign_positive());
+    assert_eq!(expect_num("round_half_even", vec![num(-1.5)]), -2.0);
+    assert_eq!(expect_num("round_half_even", vec![num(-2.5)]), -2.0);
+}
+
+#[test]
+fn test_round_half_even_non_ties_match_round() {
+    for x in [3.2, 3.7, -3.2, -3.7, 5.0, 0.0, 2.4999] {
+        assert_eq!(
+            expect_num("round_half_even", vec![num(x)]),
+            expect_num("round", vec![num(x)]),
+            "mismatch at {x}"
+        );
+    }
+}
+
+#[test]
+fn test_round_half_even_wrong_args() {
+    let m = MathModule::new();
+    assert!(m.call("round_half_even", vec![]).is_err());
+    assert!(m
+        .call("round_half_even", vec![Value::String("1".into())])
+        .is_err());
+}
+
 // ══════════════════════════════════════════════════════════════════════════════
 // math.round_to
 // ══════════════════════════════════════════════════════════════════════════════
@@ -479,6 +547,432 @@ fn test_sqrt_small_negative_trap() {
     assert!(matches!(err, StdlibError::RuntimeError(_)));
 }
 
+// ══════════════════════════════════════════════════════════════════════════════
+// math.map_range
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_map_range_basic() {
+    let result = expect_num(
+        "map_range",
+        vec![num(5.0), num(0.0), num(10.0), num(0.0), num(100.0)],
+    );
+    assert_eq!(result, 50.0);
+}
+
+#[test]
+fn test_map_range_reversed_output() {
+    let result = expect_num(
+        "map_range",
+        vec![num(2.5), num(0.0), num(10.0), num(100.0), num(0.0)],
+    );
+    assert_eq!(result, 75.0);
+}
+
+#[test]
+fn test_map_range_zero_width_trap() {
+    let err = call(
+        "map_range",
+        vec![num(5.0), num(3.0), num(3.0), num(0.0), num(1.0)],
+    )
+    .unwrap_err();
+    assert!(matches!(err, StdlibError::RuntimeError(_)));
+}
+
+#[test]
+fn test_map_range_wrong_args() {
+    let err = call("map_range", vec![num(1.0), num(0.0)]).unwrap_err();
+    assert!(matches!(err, StdlibError::WrongArgCount { .. }));
+    let err = call(
+        "map_range",
+        vec![num(1.0), num(0.0), Value::Nil, num(0.0), num(1.0)],
+    )
+    .unwrap_err();
+    assert!(matches!(err, StdlibError::TypeMismatch { position: 3, .. }));
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// math.normalize
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_normalize_bounds_and_midpoint() {
+    assert_eq!(
+        expect_num("normalize", vec![num(10.0), num(10.0), num(20.0)]),
+        0.0
+    );
+    assert_eq!(
+        expect_num("normalize", vec![num(20.0), num(10.0), num(20.0)]),
+        1.0
+    );
+    assert_eq!(
+        expect_num("normalize", vec![num(15.0), num(10.0), num(20.0)]),
+        0.5
+    );
+}
+
+#[test]
+fn test_normalize_clamps_outside_range() {
+    assert_eq!(
+        expect_num("normalize", vec![num(5.0), num(10.0), num(20.0)]),
+        0.0
+    );
+    assert_eq!(
+        expect_num("normalize", vec![num(25.0), num(10.0), num(20.0)]),
+        1.0
+    );
+}
+
+#[test]
+fn test_normalize_reversed_range() {
+    assert_eq!(
+        expect_num("normalize", vec![num(15.0), num(20.0), num(10.0)]),
+        0.5
+    );
+    assert_eq!(
+        expect_num("normalize", vec![num(20.0), num(20.0), num(10.0)]),
+        0.0
+    );
+}
+
+#[test]
+fn test_normalize_zero_width_traps() {
+    let err = call("normalize", vec![num(5.0), num(3.0), num(3.0)]).unwrap_err();
+    assert!(matches!(err, StdlibError::RuntimeError(_)));
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// math.factorial
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_factorial_small() {
+    assert_eq!(expect_num("factorial", vec![num(0.0)]), 1.0);
+    assert_eq!(expect_num("factorial", vec![num(1.0)]), 1.0);
+    assert_eq!(expect_num("factorial", vec![num(5.0)]), 120.0);
+}
+
+#[test]
+fn test_factorial_largest_finite() {
+    assert!(expect_num("factorial", vec![num(170.0)]).is_finite());
+}
+
+#[test]
+fn test_factorial_overflow_traps() {
+    let err = call("factorial", vec![num(171.0)]).unwrap_err();
+    assert!(matches!(err, StdlibError::RuntimeError(_)));
+    let err = call("factorial", vec![num(1e12)]).unwrap_err();
+    assert!(matches!(err, StdlibError::RuntimeError(_)));
+}
+
+#[test]
+fn test_factorial_invalid_input() {
+    for bad in [-1.0, 2.5] {
+        let err = call("factorial", vec![num(bad)]).unwrap_err();
+        assert!(matches!(err, StdlibError::RuntimeError(_)));
+    }
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// math.combinations
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_combinations_basic() {
+    assert_eq!(expect_num("combinations", vec![num(5.0), num(2.0)]), 10.0);
+    assert_eq!(expect_num("combinations", vec![num(5.0), num(0.0)]), 1.0);
+    assert_eq!(expect_num("combinations", vec![num(5.0), num(5.0)]), 1.0);
+}
+
+#[test]
+fn test_combinations_k_greater_than_n() {
+    assert_eq!(expect_num("combinations", vec![num(3.0), num(5.0)]), 0.0);
+}
+
+#[test]
+fn test_combinations_beyond_factorial_range() {
+    // 200! overflows, but C(200, 2) is small
+    assert_eq!(
+        expect_num("combinations", vec![num(200.0), num(2.0)]),
+        19900.0
+    );
+}
+
+#[test]
+fn test_combinations_invalid_input() {
+    let err = call("combinations", vec![num(5.0), num(-1.0)]).unwrap_err();
+    assert!(matches!(err, StdlibError::RuntimeError(_)));
+    let err = call("combinations", vec![num(5.0)]).unwrap_err();
+    assert!(matches!(err, StdlibError::WrongArgCount { .. }));
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// math.is_close
+// ══════════════════════════════════════════════════════════════════════════════
+
+fn is_close(a: f64, b: f64, rel_tol: f64) -> bool {
+    match call_ok("is_close", vec![num(a), num(b), num(rel_tol)]) {
+        Value::Bool(b) => b,
+        other => panic!("expected Bool, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_is_close_exactly_equal() {
+    assert!(is_close(1.5, 1.5, 0.0));
+    assert!(is_close(-3.0, -3.0, 1e-9));
+}
+
+#[test]
+fn test_is_close_within_tolerance() {
+    assert!(is_close(0.1 + 0.2, 0.3, 1e-9));
+    assert!(is_close(100.0, 100.9, 0.01));
+}
+
+#[test]
+fn test_is_close_just_outside() {
+    assert!(!is_close(100.0, 101.1, 0.01));
+    assert!(!is_close(1.0, 1.0001, 1e-9));
+}
+
+#[test]
+fn test_is_close_near_zero() {
+    // A purely relative test would demand exact equality against zero
+    assert!(is_close(0.0, 1e-15, 1e-9));
+    assert!(!is_close(0.0, 1e-6, 1e-9));
+}
+
+#[test]
+fn test_is_close_negative_tolerance() {
+    let err = call("is_close", vec![num(1.0), num(1.0), num(-0.1)]).unwrap_err();
+    assert!(matches!(err, StdlibError::RuntimeError(_)));
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// math.clamp01
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_clamp01_below_zero() {
+    assert_eq!(expect_num("clamp01", vec![num(-0.5)]), 0.0);
+}
+
+#[test]
+fn test_clamp01_above_one() {
+    assert_eq!(expect_num("clamp01", vec![num(1.7)]), 1.0);
+}
+
+#[test]
+fn test_clamp01_within_range() {
+    assert_eq!(expect_num("clamp01", vec![num(0.25)]), 0.25);
+}
+
+#[test]
+fn test_saturate_alias() {
+    assert_eq!(expect_num("saturate", vec![num(-2.0)]), 0.0);
+    assert_eq!(expect_num("saturate", vec![num(0.4)]), 0.4);
+}
+
+#[test]
+fn test_clamp01_boundaries() {
+    assert_eq!(expect_num("clamp01", vec![num(0.0)]), 0.0);
+    assert_eq!(expect_num("clamp01", vec![num(1.0)]), 1.0);
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// math.degrees / math.radians
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_degrees_of_pi() {
+    assert_eq!(
+        expect_num("degrees", vec![num(std::f64::consts::PI)]),
+        180.0
+    );
+    assert_eq!(expect_num("degrees", vec![num(0.0)]), 0.0);
+}
+
+#[test]
+fn test_radians_of_180() {
+    let r = expect_num("radians", vec![num(180.0)]);
+    assert!((r - std::f64::consts::PI).abs() < 1e-12);
+    assert_eq!(
+        expect_num("radians", vec![num(-90.0)]),
+        -std::f64::consts::FRAC_PI_2
+    );
+}
+
+#[test]
+fn test_degrees_radians_round_trip() {
+    for deg in [0.0, 1.0, 45.0, 90.0, 123.456, -270.0, 720.0] {
+        let rad = expect_num("radians", vec![num(deg)]);
+        let back = expect_num("degrees", vec![num(rad)]);
+        assert!((back - deg).abs() < 1e-9, "{deg} → {rad} → {back}");
+    }
+}
+
+#[test]
+fn test_degrees_radians_trap_on_overflow() {
+    assert!(call("degrees", vec![num(f64::MAX)]).is_err());
+    assert!(call("radians", vec![num(f64::NAN)]).is_err());
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// math.clamp_magnitude
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_clamp_magnitude_within_cap() {
+    assert_eq!(expect_num("clamp_magnitude", vec![num(3.0), num(5.0)]), 3.0);
+    assert_eq!(
+        expect_num("clamp_magnitude", vec![num(-3.0), num(5.0)]),
+        -3.0
+    );
+    assert_eq!(expect_num("clamp_magnitude", vec![num(5.0), num(5.0)]), 5.0);
+}
+
+#[test]
+fn test_clamp_magnitude_over_cap_keeps_sign() {
+    assert_eq!(
+        expect_num("clamp_magnitude", vec![num(12.0), num(5.0)]),
+        5.0
+    );
+    assert_eq!(
+        expect_num("clamp_magnitude", vec![num(-12.0), num(5.0)]),
+        -5.0
+    );
+}
+
+#[test]
+fn test_clamp_magnitude_zero() {
+    assert_eq!(expect_num("clamp_magnitude", vec![num(0.0), num(5.0)]), 0.0);
+    assert_eq!(expect_num("clamp_magnitude", vec![num(4.0), num(0.0)]), 0.0);
+}
+
+#[test]
+fn test_clamp_magnitude_negative_max_traps() {
+    let err = call("clamp_magnitude", vec![num(1.0), num(-1.0)]).unwrap_err();
+    assert!(matches!(err, StdlibError::RuntimeError(_)));
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// math.wrap
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_wrap_above_range() {
+    assert_eq!(
+        expect_num("wrap", vec![num(370.0), num(0.0), num(360.0)]),
+        10.0
+    );
+    assert_eq!(
+        expect_num("wrap", vec![num(360.0), num(0.0), num(360.0)]),
+        0.0
+    );
+}
+
+#[test]
+fn test_wrap_negative() {
+    assert_eq!(
+        expect_num("wrap", vec![num(-10.0), num(0.0), num(360.0)]),
+        350.0
+    );
+    assert_eq!(expect_num("wrap", vec![num(-1.0), num(1.0), num(4.0)]), 2.0);
+}
+
+#[test]
+fn test_wrap_in_range_unchanged() {
+    assert_eq!(
+        expect_num("wrap", vec![num(45.0), num(0.0), num(360.0)]),
+        45.0
+    );
+    assert_eq!(
+        expect_num("wrap", vec![num(-5.0), num(-10.0), num(10.0)]),
+        -5.0
+    );
+}
+
+#[test]
+fn test_wrap_invalid_range_traps() {
+    for (min, max) in [(5.0, 5.0), (10.0, 0.0)] {
+        let err = call("wrap", vec![num(1.0), num(min), num(max)]).unwrap_err();
+        assert!(matches!(err, StdlibError::RuntimeError(_)));
+    }
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// math.snap_integer
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_snap_integer_within_tolerance() {
+    assert_eq!(
+        expect_num("snap_integer", vec![num(2.9999999), num(1e-6)]),
+        3.0
+    );
+    assert_eq!(
+        expect_num("snap_integer", vec![num(3.0000001), num(1e-6)]),
+        3.0
+    );
+    assert_eq!(
+        expect_num("snap_integer", vec![num(-4.0000001), num(1e-6)]),
+        -4.0
+    );
+}
+
+#[test]
+fn test_snap_integer_outside_tolerance_unchanged() {
+    assert_eq!(expect_num("snap_integer", vec![num(2.5), num(1e-6)]), 2.5);
+    assert_eq!(expect_num("snap_integer", vec![num(2.99), num(1e-6)]), 2.99);
+}
+
+#[test]
+fn test_snap_integer_negative_tolerance_traps() {
+    let err = call("snap_integer", vec![num(1.0), num(-0.1)]).unwrap_err();
+    assert!(matches!(err, StdlibError::RuntimeError(_)));
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// math.floor_to / math.ceil_to
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_floor_ceil_to_quarter_step() {
+    assert_eq!(expect_num("floor_to", vec![num(1.3), num(0.25)]), 1.25);
+    assert_eq!(expect_num("ceil_to", vec![num(1.3), num(0.25)]), 1.5);
+}
+
+#[test]
+fn test_floor_ceil_to_negative_values() {
+    assert_eq!(expect_num("floor_to", vec![num(-1.3), num(0.25)]), -1.5);
+    assert_eq!(expect_num("ceil_to", vec![num(-1.3), num(0.25)]), -1.25);
+    assert_eq!(expect_num("floor_to", vec![num(-7.0), num(5.0)]), -10.0);
+    let zero = expect_num("ceil_to", vec![num(-0.1), num(1.0)]);
+    assert!(zero == 0.0 && zero.is_sign_positive());
+}
+
+#[test]
+fn test_floor_ceil_to_exact_multiples_unchanged() {
+    assert_eq!(expect_num("floor_to", vec![num(1.5), num(0.25)]), 1.5);
+    assert_eq!(expect_num("ceil_to", vec![num(1.5), num(0.25)]), 1.5);
+    // 0.3 / 0.1 is 2.9999999999999996 in floating point
+    assert!((expect_num("floor_to", vec![num(0.3), num(0.1)]) - 0.3).abs() < 1e-12);
+    assert!((expect_num("ceil_to", vec![num(0.3), num(0.1)]) - 0.3).abs() < 1e-12);
+}
+
+#[test]
+fn test_floor_ceil_to_non_positive_step_traps() {
+    for f in ["floor_to", "ceil_to"] {
+        for step in [0.0, -0.25] {
+            let err = call(f, vec![num(1.0), num(step)]).unwrap_err();
+            assert!(
+                matches!(err, StdlibError::RuntimeError(_)),
+                "{f} step {step}"
+            );
+        }
+    }
+}
+
 // ══════════════════════════════════════════════════════════════════════════════
 // math.PI and math.E constants
 // ══════════════════════════════════════════════════════════════════════════════
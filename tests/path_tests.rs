@@ -0,0 +1,143 @@
+//! Tests for the `path` stdlib module.
+
+use pepl_stdlib::modules::path::PathModule;
+use pepl_stdlib::{StdlibError, StdlibModule, Value};
+
+// ── Helpers ──────────────────────────────────────────────────────────────────
+
+fn call(func: &str, args: Vec<Value>) -> Result<Value, StdlibError> {
+    PathModule::new().call(func, args)
+}
+
+fn s(v: &str) -> Value {
+    Value::String(v.to_string())
+}
+
+fn strs(items: &[&str]) -> Value {
+    Value::List(items.iter().map(|v| s(v)).collect())
+}
+
+fn path_str(func: &str, p: &str) -> Value {
+    call(func, vec![s(p)]).unwrap()
+}
+
+// ── path.join ────────────────────────────────────────────────────────────────
+
+#[test]
+fn join_nested() {
+    assert_eq!(
+        call("join", vec![strs(&["users", "42", "prefs.json"])]).unwrap(),
+        s("users/42/prefs.json")
+    );
+}
+
+#[test]
+fn join_collapses_slashes_at_joins() {
+    assert_eq!(
+        call("join", vec![strs(&["/a/", "/b/", "c"])]).unwrap(),
+        s("/a/b/c")
+    );
+    assert_eq!(call("join", vec![strs(&["a", "b/"])]).unwrap(), s("a/b/"));
+}
+
+#[test]
+fn join_skips_empty_parts() {
+    assert_eq!(
+        call("join", vec![strs(&["", "a", "", "b"])]).unwrap(),
+        s("a/b")
+    );
+    assert_eq!(call("join", vec![strs(&[])]).unwrap(), s(""));
+}
+
+#[test]
+fn join_rejects_non_strings() {
+    let parts = Value::List(vec![s("a"), Value::Number(1.0)]);
+    assert!(matches!(
+        call("join", vec![parts]),
+        Err(StdlibError::RuntimeError(_))
+    ));
+}
+
+// ── path.basename / path.dirname ─────────────────────────────────────────────
+
+#[test]
+fn basename_cases() {
+    assert_eq!(path_str("basename", "a/b/c.txt"), s("c.txt"));
+    assert_eq!(path_str("basename", "a/b/"), s("b"));
+    assert_eq!(path_str("basename", "file"), s("file"));
+    assert_eq!(path_str("basename", "/"), s(""));
+    assert_eq!(path_str("basename", ""), s(""));
+}
+
+#[test]
+fn dirname_cases() {
+    assert_eq!(path_str("dirname", "a/b/c.txt"), s("a/b"));
+    assert_eq!(path_str("dirname", "a/b/"), s("a"));
+    assert_eq!(path_str("dirname", "/a"), s("/"));
+    assert_eq!(path_str("dirname", "a"), s(""));
+    assert_eq!(path_str("dirname", "/"), s("/"));
+    assert_eq!(path_str("dirname", ""), s(""));
+}
+
+// ── path.extension ───────────────────────────────────────────────────────────
+
+#[test]
+fn extension_extraction() {
+    assert_eq!(path_str("extension", "docs/report.pdf"), s("pdf"));
+    assert_eq!(path_str("extension", "archive.tar.gz"), s("gz"));
+    assert_eq!(path_str("extension", "dir.d/file"), Value::Nil);
+}
+
+#[test]
+fn extension_edge_cases() {
+    assert_eq!(path_str("extension", ".env"), Value::Nil);
+    assert_eq!(path_str("extension", "file."), Value::Nil);
+    assert_eq!(path_str("extension", ""), Value::Nil);
+}
+
+// ── path.split ───────────────────────────────────────────────────────────────
+
+#[test]
+fn split_segments() {
+    assert_eq!(path_str("split", "a/b/c"), strs(&["a", "b", "c"]));
+    assert_eq!(path_str("split", "/a//b/"), strs(&["a", "b"]));
+    assert_eq!(path_str("split", "/"), strs(&[]));
+}
+
+#[test]
+fn split_join_round_trip() {
+    let parts = path_str("split", "a/b/c");
+    assert_eq!(call("join", vec![parts]).unwrap(), s("a/b/c"));
+}
+
+// ── Module ───────────────────────────────────────────────────────────────────
+
+#[test]
+fn path_has_function() {
+    let m = PathModule::new();
+    assert_eq!(m.name(), "path");
+    for f in ["join", "basename", "dirname", "extension", "split"] {
+        assert!(m.has_function(f), "path should have function {f}");
+    }
+    assert!(!m.has_function("resolve"));
+}
+
+#[test]
+fn path_wrong_args() {
+    assert!(matches!(
+        call("basename", vec![]),
+        Err(StdlibError::WrongArgCount { .. })
+    ));
+    assert!(matches!(
+        call("join", vec![s("a")]),
+        Err(StdlibError::TypeMismatch { .. })
+    ));
+}
+
+#[test]
+fn path_unknown_function() {
+    assert!(matches!(
+        call("nope", vec![]),
+        Err(StdlibError::UnknownFunction { .. })
+    ));
+}
@@ -1,5 +1,7 @@
 //! Tests for Phase 5 stdlib modules: record, time, convert, json, timer.
 
+#![allow(clippy::approx_constant)] // 3.14 etc. are arbitrary decimals, not PI
+
 use std::collections::BTreeMap;
 
 use pepl_stdlib::modules::convert::ConvertModule;
@@ -7,7 +9,7 @@ use pepl_stdlib::modules::json::JsonModule;
 use pepl_stdlib::modules::record::RecordModule;
 use pepl_stdlib::modules::time::TimeModule;
 use pepl_stdlib::modules::timer::TimerModule;
-use pepl_stdlib::{StdlibModule, Value};
+use pepl_stdlib::{StdlibError, StdlibFn, StdlibModule, Value};
 
 // ══════════════════════════════════════════════════════════════════════════════
 // Helpers
@@ -128,6 +130,234 @@ fn record_empty_record_keys_and_values() {
     assert_eq!(m.call("values", vec![r]).unwrap(), Value::List(vec![]));
 }
 
+#[test]
+fn record_deep_get_two_levels() {
+    let m = RecordModule::new();
+    let r = rec(vec![("config", rec(vec![("theme", s("dark"))]))]);
+    let path = Value::List(vec![s("config"), s("theme")]);
+    assert_eq!(m.call("deep_get", vec![r, path]).unwrap(), s("dark"));
+}
+
+#[test]
+fn record_deep_get_missing_intermediate_returns_nil() {
+    let m = RecordModule::new();
+    let r = rec(vec![("config", rec(vec![]))]);
+    let path = Value::List(vec![s("missing"), s("theme")]);
+    assert_eq!(m.call("deep_get", vec![r, path]).unwrap(), Value::Nil);
+}
+
+#[test]
+fn record_deep_set_two_levels() {
+    let m = RecordModule::new();
+    let r = rec(vec![(
+        "config",
+        rec(vec![("theme", s("dark")), ("size", n(12.0))]),
+    )]);
+    let path = Value::List(vec![s("config"), s("theme")]);
+    let result = m.call("deep_set", vec![r, path, s("light")]).unwrap();
+    assert_eq!(
+        result,
+        rec(vec![(
+            "config",
+            rec(vec![("theme", s("light")), ("size", n(12.0))]),
+        )])
+    );
+}
+
+#[test]
+fn record_deep_set_creates_missing_intermediates() {
+    let m = RecordModule::new();
+    let path = Value::List(vec![s("a"), s("b"), s("c")]);
+    let result = m.call("deep_set", vec![rec(vec![]), path, n(1.0)]).unwrap();
+    assert_eq!(
+        result,
+        rec(vec![("a", rec(vec![("b", rec(vec![("c", n(1.0))]))]))])
+    );
+}
+
+#[test]
+fn record_deep_set_does_not_mutate_original() {
+    let m = RecordModule::new();
+    let original = rec(vec![("config", rec(vec![("theme", s("dark"))]))]);
+    let path = Value::List(vec![s("config"), s("theme")]);
+    let _ = m
+        .call("deep_set", vec![original.clone(), path.clone(), s("light")])
+        .unwrap();
+    assert_eq!(m.call("deep_get", vec![original, path]).unwrap(), s("dark"));
+}
+
+#[test]
+fn record_deep_set_errors() {
+    let m = RecordModule::new();
+    let r = rec(vec![("a", n(1.0))]);
+    // Empty path
+    assert!(m
+        .call("deep_set", vec![r.clone(), Value::List(vec![]), n(2.0)])
+        .is_err());
+    // Descending into a non-record
+    let path = Value::List(vec![s("a"), s("b")]);
+    assert!(m.call("deep_set", vec![r.clone(), path, n(2.0)]).is_err());
+    // Non-string path segment
+    let path = Value::List(vec![n(0.0)]);
+    assert!(m.call("deep_get", vec![r, path]).is_err());
+}
+
+#[test]
+fn record_rename_key_present() {
+    let m = RecordModule::new();
+    let r = rec(vec![("name", s("Ada")), ("age", n(36.0))]);
+    let result = m
+        .call("rename_key", vec![r, s("name"), s("full_name")])
+        .unwrap();
+    assert_eq!(result, rec(vec![("full_name", s("Ada")), ("age", n(36.0))]));
+}
+
+#[test]
+fn record_rename_key_absent_is_noop() {
+    let m = RecordModule::new();
+    let r = rec(vec![("name", s("Ada"))]);
+    let result = m
+        .call("rename_key", vec![r.clone(), s("missing"), s("other")])
+        .unwrap();
+    assert_eq!(result, r);
+}
+
+#[test]
+fn record_rename_key_overwrites_existing() {
+    let m = RecordModule::new();
+    let r = rec(vec![("a", n(1.0)), ("b", n(2.0))]);
+    let result = m.call("rename_key", vec![r, s("a"), s("b")]).unwrap();
+    assert_eq!(result, rec(vec![("b", n(1.0))]));
+}
+
+#[test]
+fn record_equals_ignoring_only_ignored_key_differs() {
+    let m = RecordModule::new();
+    let a = rec(vec![("id", n(1.0)), ("name", s("Ada")), ("ts", n(100.0))]);
+    let c = rec(vec![("id", n(2.0)), ("name", s("Ada")), ("ts", n(200.0))]);
+    let ignore = Value::List(vec![s("id"), s("ts")]);
+    assert_eq!(
+        m.call("equals_ignoring", vec![a, c, ignore]).unwrap(),
+        b(true)
+    );
+}
+
+#[test]
+fn record_equals_ignoring_other_key_differs() {
+    let m = RecordModule::new();
+    let a = rec(vec![("id", n(1.0)), ("name", s("Ada"))]);
+    let c = rec(vec![("id", n(2.0)), ("name", s("Grace"))]);
+    let ignore = Value::List(vec![s("id")]);
+    assert_eq!(
+        m.call("equals_ignoring", vec![a, c, ignore]).unwrap(),
+        b(false)
+    );
+}
+
+#[test]
+fn record_equals_ignoring_different_key_sets() {
+    let m = RecordModule::new();
+    let a = rec(vec![("name", s("Ada")), ("ts", n(1.0))]);
+    let c = rec(vec![("name", s("Ada")), ("extra", b(true))]);
+    let only_ts = Value::List(vec![s("ts")]);
+    assert_eq!(
+        m.call("equals_ignoring", vec![a.clone(), c.clone(), only_ts])
+            .unwrap(),
+        b(false)
+    );
+    // A key present on only one side is fine once it is ignored
+    let both = Value::List(vec![s("ts"), s("extra")]);
+    assert_eq!(
+        m.call("equals_ignoring", vec![a, c, both]).unwrap(),
+        b(true)
+    );
+}
+
+#[test]
+fn record_equals_ignoring_rejects_non_string_keys() {
+    let m = RecordModule::new();
+    let ignore = Value::List(vec![n(1.0)]);
+    assert!(m
+        .call("equals_ignoring", vec![rec(vec![]), rec(vec![]), ignore])
+        .is_err());
+}
+
+#[test]
+fn record_to_string_pairs_stringifies_values() {
+    let m = RecordModule::new();
+    let r = rec(vec![
+        ("page", n(2.0)),
+        ("active", b(true)),
+        ("q", s("rust lang")),
+    ]);
+    let pair = |k: &str, v: &str| rec(vec![("key", s(k)), ("value", s(v))]);
+    assert_eq!(
+        m.call("to_string_pairs", vec![r]).unwrap(),
+        Value::List(vec![
+            pair("active", "true"),
+            pair("page", "2"),
+            pair("q", "rust lang"),
+        ])
+    );
+}
+
+#[test]
+fn record_to_string_pairs_empty() {
+    let m = RecordModule::new();
+    assert_eq!(
+        m.call("to_string_pairs", vec![rec(vec![])]).unwrap(),
+        Value::List(vec![])
+    );
+    assert!(m.call("to_string_pairs", vec![n(1.0)]).is_err());
+}
+
+#[test]
+fn record_map_keys_uppercases_and_preserves_values() {
+    let m = RecordModule::new();
+    let upper = Value::Function(StdlibFn::new(|args| {
+        Ok(s(&args[0].as_str().unwrap().to_uppercase()))
+    }));
+    let r = rec(vec![("name", s("Ada")), ("age", n(36.0))]);
+    assert_eq!(
+        m.call("map_keys", vec![r, upper]).unwrap(),
+        rec(vec![("NAME", s("Ada")), ("AGE", n(36.0))])
+    );
+}
+
+#[test]
+fn record_map_keys_collision_later_wins() {
+    let m = RecordModule::new();
+    let constant = Value::Function(StdlibFn::new(|_| Ok(s("k"))));
+    // "a" is visited before "b", so b's value wins
+    let r = rec(vec![("a", n(1.0)), ("b", n(2.0))]);
+    assert_eq!(
+        m.call("map_keys", vec![r, constant]).unwrap(),
+        rec(vec![("k", n(2.0))])
+    );
+}
+
+#[test]
+fn record_map_keys_errors() {
+    let m = RecordModule::new();
+    let r = rec(vec![("a", n(1.0))]);
+    let not_string = Value::Function(StdlibFn::new(|_| Ok(n(1.0))));
+    assert!(m.call("map_keys", vec![r.clone(), not_string]).is_err());
+    let failing = Value::Function(StdlibFn::new(|_| {
+        Err(StdlibError::RuntimeError("boom".into()))
+    }));
+    let err = m.call("map_keys", vec![r, failing]).unwrap_err();
+    assert_eq!(err.to_string(), "boom");
+}
+
+#[test]
+fn record_map_keys_rejects_callback_with_wrong_arity() {
+    let m = RecordModule::new();
+    let r = rec(vec![("a", n(1.0))]);
+    let two_args = Value::Function(StdlibFn::new(|args| Ok(args[0].clone())).with_arity(2));
+    let err = m.call("map_keys", vec![r, two_args]).unwrap_err();
+    assert!(matches!(err, StdlibError::RuntimeError(msg) if msg.contains("takes 2 arguments,")));
+}
+
 #[test]
 fn record_wrong_arg_count() {
     let m = RecordModule::new();
@@ -136,6 +366,7 @@ fn record_wrong_arg_count() {
     assert!(m.call("has", vec![]).is_err());
     assert!(m.call("keys", vec![]).is_err());
     assert!(m.call("values", vec![]).is_err());
+    assert!(m.call("rename_key", vec![rec(vec![]), s("a")]).is_err());
 }
 
 #[test]
@@ -159,6 +390,12 @@ fn record_has_function() {
     assert!(m.has_function("has"));
     assert!(m.has_function("keys"));
     assert!(m.has_function("values"));
+    assert!(m.has_function("deep_get"));
+    assert!(m.has_function("deep_set"));
+    assert!(m.has_function("rename_key"));
+    assert!(m.has_function("equals_ignoring"));
+    assert!(m.has_function("to_string_pairs"));
+    assert!(m.has_function("map_keys"));
     assert!(!m.has_function("delete"));
     assert_eq!(m.name(), "record");
 }
@@ -171,6 +408,15 @@ fn record_has_function() {
 fn time_now_returns_zero_stub() {
     let m = TimeModule::new();
     assert_eq!(m.call("now", vec![]).unwrap(), n(0.0));
+    assert_eq!(TimeModule::default().call("now", vec![]).unwrap(), n(0.0));
+}
+
+#[test]
+fn time_now_injected() {
+    let m = TimeModule::with_now(1_700_000_000_000.0);
+    assert_eq!(m.call("now", vec![]).unwrap(), n(1_700_000_000_000.0));
+    // Fixed per instance — repeated calls agree
+    assert_eq!(m.call("now", vec![]).unwrap(), n(1_700_000_000_000.0));
 }
 
 #[test]
@@ -232,6 +478,203 @@ fn time_format_datetime() {
     assert_eq!(result, s("1970-01-01 00:00:00"));
 }
 
+#[test]
+fn time_relative_just_now() {
+    let m = TimeModule::new();
+    assert_eq!(
+        m.call("relative", vec![n(0.0), n(0.0)]).unwrap(),
+        s("just now")
+    );
+    assert_eq!(
+        m.call("relative", vec![n(0.0), n(59_999.0)]).unwrap(),
+        s("just now")
+    );
+}
+
+#[test]
+fn time_relative_minutes() {
+    let m = TimeModule::new();
+    assert_eq!(
+        m.call("relative", vec![n(0.0), n(60_000.0)]).unwrap(),
+        s("1 minute ago")
+    );
+    assert_eq!(
+        m.call("relative", vec![n(0.0), n(5.0 * 60_000.0)]).unwrap(),
+        s("5 minutes ago")
+    );
+    assert_eq!(
+        m.call("relative", vec![n(0.0), n(3_599_999.0)]).unwrap(),
+        s("59 minutes ago")
+    );
+}
+
+#[test]
+fn time_relative_hours() {
+    let m = TimeModule::new();
+    assert_eq!(
+        m.call("relative", vec![n(0.0), n(3_600_000.0)]).unwrap(),
+        s("1 hour ago")
+    );
+    assert_eq!(
+        m.call("relative", vec![n(0.0), n(86_399_999.0)]).unwrap(),
+        s("23 hours ago")
+    );
+}
+
+#[test]
+fn time_relative_days() {
+    let m = TimeModule::new();
+    assert_eq!(
+        m.call("relative", vec![n(0.0), n(86_400_000.0)]).unwrap(),
+        s("1 day ago")
+    );
+    assert_eq!(
+        m.call("relative", vec![n(0.0), n(3.0 * 86_400_000.0)])
+            .unwrap(),
+        s("3 days ago")
+    );
+}
+
+#[test]
+fn time_relative_future() {
+    let m = TimeModule::new();
+    assert_eq!(
+        m.call("relative", vec![n(30_000.0), n(0.0)]).unwrap(),
+        s("just now")
+    );
+    assert_eq!(
+        m.call("relative", vec![n(5.0 * 60_000.0), n(0.0)]).unwrap(),
+        s("in 5 minutes")
+    );
+    assert_eq!(
+        m.call("relative", vec![n(2.0 * 3_600_000.0), n(0.0)])
+            .unwrap(),
+        s("in 2 hours")
+    );
+    assert_eq!(
+        m.call("relative", vec![n(86_400_000.0), n(0.0)]).unwrap(),
+        s("in 1 day")
+    );
+}
+
+#[test]
+fn time_duration_format_zero() {
+    let m = TimeModule::new();
+    assert_eq!(m.call("duration_format", vec![n(0.0)]).unwrap(), s("0:00"));
+}
+
+#[test]
+fn time_duration_format_ninety_seconds() {
+    let m = TimeModule::new();
+    assert_eq!(
+        m.call("duration_format", vec![n(90_000.0)]).unwrap(),
+        s("1:30")
+    );
+}
+
+#[test]
+fn time_duration_format_one_hour() {
+    let m = TimeModule::new();
+    assert_eq!(
+        m.call("duration_format", vec![n(3_600_000.0)]).unwrap(),
+        s("1:00:00")
+    );
+}
+
+#[test]
+fn time_duration_format_multi_hour() {
+    let m = TimeModule::new();
+    // 12h 5m 9.5s — sub-second part is truncated
+    let ms = (12.0 * 3600.0 + 5.0 * 60.0 + 9.5) * 1000.0;
+    assert_eq!(
+        m.call("duration_format", vec![n(ms)]).unwrap(),
+        s("12:05:09")
+    );
+}
+
+#[test]
+fn time_duration_format_negative_traps() {
+    let m = TimeModule::new();
+    assert!(m.call("duration_format", vec![n(-1.0)]).is_err());
+}
+
+#[test]
+fn time_between_inside_and_outside() {
+    let m = TimeModule::new();
+    let between = |ts: f64| m.call("between", vec![n(ts), n(100.0), n(200.0)]).unwrap();
+    assert_eq!(between(150.0), b(true));
+    assert_eq!(between(50.0), b(false));
+    assert_eq!(between(250.0), b(false));
+}
+
+#[test]
+fn time_between_is_half_open() {
+    let m = TimeModule::new();
+    let between = |ts: f64| m.call("between", vec![n(ts), n(100.0), n(200.0)]).unwrap();
+    assert_eq!(between(100.0), b(true));
+    assert_eq!(between(200.0), b(false));
+    // Empty window matches nothing
+    assert_eq!(
+        m.call("between", vec![n(100.0), n(100.0), n(100.0)])
+            .unwrap(),
+        b(false)
+    );
+}
+
+#[test]
+fn time_between_reversed_range_traps() {
+    let m = TimeModule::new();
+    let err = m
+        .call("between", vec![n(150.0), n(200.0), n(100.0)])
+        .unwrap_err();
+    assert!(matches!(err, pepl_stdlib::StdlibError::RuntimeError(_)));
+}
+
+#[test]
+fn time_quarter_boundaries() {
+    let m = TimeModule::new();
+    // 2024-03-31 23:59:59 / 2024-04-01 00:00:00
+    assert_eq!(m.call("quarter", vec![n(1711929599000.0)]).unwrap(), n(1.0));
+    assert_eq!(m.call("quarter", vec![n(1711929600000.0)]).unwrap(), n(2.0));
+    // 2024-07-01
+    assert_eq!(m.call("quarter", vec![n(1719792000000.0)]).unwrap(), n(3.0));
+    // 2024-12-31 12:00
+    assert_eq!(m.call("quarter", vec![n(1735646400000.0)]).unwrap(), n(4.0));
+}
+
+#[test]
+fn time_week_of_year_mid_year() {
+    let m = TimeModule::new();
+    // 2024-04-01 (Monday) starts ISO week 14; the Sunday before ends week 13
+    assert_eq!(
+        m.call("week_of_year", vec![n(1711929600000.0)]).unwrap(),
+        n(14.0)
+    );
+    assert_eq!(
+        m.call("week_of_year", vec![n(1711929599000.0)]).unwrap(),
+        n(13.0)
+    );
+    // 1970-01-01 (Thursday)
+    assert_eq!(m.call("week_of_year", vec![n(0.0)]).unwrap(), n(1.0));
+}
+
+#[test]
+fn time_week_of_year_year_transitions() {
+    let m = TimeModule::new();
+    let week = |ts: f64| m.call("week_of_year", vec![n(ts)]).unwrap();
+    // 2021-01-03 (Sunday) still belongs to 2020-W53; 2021-01-04 starts W1
+    assert_eq!(week(1609632000000.0), n(53.0));
+    assert_eq!(week(1609718400000.0), n(1.0));
+    // 2024-12-30 (Monday) is 2025-W01
+    assert_eq!(week(1735516800000.0), n(1.0));
+    // 2026-12-31 (Thursday) is W53 of a long year
+    assert_eq!(week(1798675200000.0), n(53.0));
+    // 2023-01-01 (Sunday) is 2022-W52
+    assert_eq!(week(1672531200000.0), n(52.0));
+    // 1969-12-28 (Sunday, before the epoch) is 1969-W52
+    assert_eq!(week(-345600000.0), n(52.0));
+}
+
 #[test]
 fn time_wrong_arg_count() {
     let m = TimeModule::new();
@@ -257,6 +700,11 @@ fn time_has_function() {
     assert!(m.has_function("diff"));
     assert!(m.has_function("day_of_week"));
     assert!(m.has_function("start_of_day"));
+    assert!(m.has_function("relative"));
+    assert!(m.has_function("duration_format"));
+    assert!(m.has_function("between"));
+    assert!(m.has_function("quarter"));
+    assert!(m.has_function("week_of_year"));
     assert!(!m.has_function("sleep"));
     assert_eq!(m.name(), "time");
 }
@@ -412,64 +860,441 @@ fn convert_to_bool_falsy() {
 }
 
 #[test]
-fn convert_wrong_arg_count() {
+fn convert_to_i32_in_range() {
     let m = ConvertModule::new();
-    assert!(m.call("to_string", vec![]).is_err());
-    assert!(m.call("to_number", vec![]).is_err());
-    assert!(m.call("parse_int", vec![]).is_err());
-    assert!(m.call("parse_float", vec![]).is_err());
-    assert!(m.call("to_bool", vec![]).is_err());
+    assert_eq!(unwrap_ok(m.call("to_i32", vec![n(42.0)]).unwrap()), n(42.0));
+    assert_eq!(unwrap_ok(m.call("to_i32", vec![n(-7.0)]).unwrap()), n(-7.0));
 }
 
 #[test]
-fn convert_parse_int_wrong_type() {
+fn convert_to_i32_boundaries() {
     let m = ConvertModule::new();
-    assert!(m.call("parse_int", vec![n(1.0)]).is_err());
+    let max = i32::MAX as f64;
+    let min = i32::MIN as f64;
+    assert_eq!(unwrap_ok(m.call("to_i32", vec![n(max)]).unwrap()), n(max));
+    assert_eq!(unwrap_ok(m.call("to_i32", vec![n(min)]).unwrap()), n(min));
 }
 
 #[test]
-fn convert_has_function() {
+fn convert_to_i32_out_of_range() {
     let m = ConvertModule::new();
-    assert!(m.has_function("to_string"));
-    assert!(m.has_function("to_number"));
-    assert!(m.has_function("parse_int"));
-    assert!(m.has_function("parse_float"));
-    assert!(m.has_function("to_bool"));
-    assert!(!m.has_function("cast"));
-    assert_eq!(m.name(), "convert");
+    assert!(is_err(
+        &m.call("to_i32", vec![n(i32::MAX as f64 + 1.0)]).unwrap()
+    ));
+    assert!(is_err(
+        &m.call("to_i32", vec![n(i32::MIN as f64 - 1.0)]).unwrap()
+    ));
 }
 
-// ══════════════════════════════════════════════════════════════════════════════
-// json module
-// ══════════════════════════════════════════════════════════════════════════════
-
 #[test]
-fn json_parse_object() {
-    let m = JsonModule::new();
-    let result = m
-        .call("parse", vec![s(r#"{"a": 1, "b": "hello"}"#)])
-        .unwrap();
-    assert!(is_ok(&result));
-    let val = unwrap_ok(result);
-    match &val {
-        Value::Record { fields, .. } => {
-            assert_eq!(fields.get("a"), Some(&n(1.0)));
-            assert_eq!(fields.get("b"), Some(&s("hello")));
-        }
-        _ => panic!("expected record, got {:?}", val),
-    }
+fn convert_to_i32_truncates_toward_zero() {
+    let m = ConvertModule::new();
+    assert_eq!(unwrap_ok(m.call("to_i32", vec![n(3.9)]).unwrap()), n(3.0));
+    assert_eq!(unwrap_ok(m.call("to_i32", vec![n(-3.9)]).unwrap()), n(-3.0));
 }
 
 #[test]
-fn json_parse_array() {
-    let m = JsonModule::new();
-    let result = m.call("parse", vec![s("[1, 2, 3]")]).unwrap();
-    let val = unwrap_ok(result);
-    assert_eq!(val, Value::List(vec![n(1.0), n(2.0), n(3.0)]));
+fn convert_to_i32_wrong_type() {
+    let m = ConvertModule::new();
+    assert!(m.call("to_i32", vec![s("42")]).is_err());
 }
 
 #[test]
-fn json_parse_primitives() {
+fn convert_format_number_grouped_decimals() {
+    let m = ConvertModule::new();
+    assert_eq!(
+        m.call("format_number", vec![n(1234.5), n(2.0), s(",")])
+            .unwrap(),
+        s("1,234.50")
+    );
+    assert_eq!(
+        m.call("format_number", vec![n(1234567.891), n(1.0), s(" ")])
+            .unwrap(),
+        s("1 234 567.9")
+    );
+}
+
+#[test]
+fn convert_format_number_zero_decimals() {
+    let m = ConvertModule::new();
+    assert_eq!(
+        m.call("format_number", vec![n(999.6), n(0.0), s(",")])
+            .unwrap(),
+        s("1,000")
+    );
+    assert_eq!(
+        m.call("format_number", vec![n(123.0), n(0.0), s("")])
+            .unwrap(),
+        s("123")
+    );
+}
+
+#[test]
+fn convert_format_number_negative() {
+    let m = ConvertModule::new();
+    assert_eq!(
+        m.call("format_number", vec![n(-1234.5), n(2.0), s(",")])
+            .unwrap(),
+        s("-1,234.50")
+    );
+    assert_eq!(
+        m.call("format_number", vec![n(-0.001), n(2.0), s(",")])
+            .unwrap(),
+        s("0.00")
+    );
+}
+
+#[test]
+fn convert_format_number_invalid_decimals() {
+    let m = ConvertModule::new();
+    assert!(m
+        .call("format_number", vec![n(1.0), n(-1.0), s(",")])
+        .is_err());
+    assert!(m
+        .call("format_number", vec![n(1.0), n(1.5), s(",")])
+        .is_err());
+}
+
+#[test]
+fn convert_format_number_caps_decimals() {
+    let m = ConvertModule::new();
+    let at_cap = m
+        .call("format_number", vec![n(1.0), n(100.0), s(",")])
+        .unwrap();
+    assert_eq!(at_cap, s(&format!("1.{}", "0".repeat(100))));
+    // Far above u16::MAX would panic inside std's formatter if not capped
+    for decimals in [101.0, 70_000.0] {
+        let err = m
+            .call("format_number", vec![n(1.0), n(decimals), s(",")])
+            .unwrap_err();
+        assert!(
+            matches!(err, StdlibError::RuntimeError(ref msg) if msg.contains("at most 100")),
+            "{err}"
+        );
+    }
+}
+
+#[test]
+fn convert_format_number_rejects_non_finite() {
+    let m = ConvertModule::new();
+    for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        let err = m
+            .call("format_number", vec![n(value), n(2.0), s(",")])
+            .unwrap_err();
+        assert!(
+            matches!(err, StdlibError::RuntimeError(ref msg) if msg.contains("finite")),
+            "{err}"
+        );
+    }
+}
+
+#[test]
+fn convert_to_number_strict_plain() {
+    let m = ConvertModule::new();
+    for (input, expected) in [("42", 42.0), ("  -7 ", -7.0), ("3.25", 3.25), ("0", 0.0)] {
+        let result = m.call("to_number_strict", vec![s(input)]).unwrap();
+        assert_eq!(unwrap_ok(result), n(expected), "input {input:?}");
+    }
+}
+
+#[test]
+fn convert_to_number_strict_scientific() {
+    // Exponents are accepted, as long as the result is finite
+    let m = ConvertModule::new();
+    let result = m.call("to_number_strict", vec![s("1.5e3")]).unwrap();
+    assert_eq!(unwrap_ok(result), n(1500.0));
+    let result = m.call("to_number_strict", vec![s("2E-2")]).unwrap();
+    assert_eq!(unwrap_ok(result), n(0.02));
+    assert!(is_err(
+        &m.call("to_number_strict", vec![s("1e400")]).unwrap()
+    ));
+}
+
+#[test]
+fn convert_to_number_strict_rejects_non_finite_literals() {
+    let m = ConvertModule::new();
+    for input in ["inf", "-inf", "nan", "NaN", "infinity", "Infinity"] {
+        let result = m.call("to_number_strict", vec![s(input)]).unwrap();
+        assert!(is_err(&result), "{input:?} should be rejected");
+    }
+}
+
+#[test]
+fn convert_to_number_strict_rejects_loose_syntax() {
+    let m = ConvertModule::new();
+    for input in ["", "+1", ".5", "5.", "1e", "1_000", "12abc", "1 2", "0x10"] {
+        let result = m.call("to_number_strict", vec![s(input)]).unwrap();
+        assert!(is_err(&result), "{input:?} should be rejected");
+    }
+    assert!(m.call("to_number_strict", vec![n(1.0)]).is_err());
+}
+
+#[test]
+fn convert_to_bool_strict_bools() {
+    let m = ConvertModule::new();
+    assert_eq!(
+        unwrap_ok(m.call("to_bool_strict", vec![b(true)]).unwrap()),
+        b(true)
+    );
+    assert_eq!(
+        unwrap_ok(m.call("to_bool_strict", vec![b(false)]).unwrap()),
+        b(false)
+    );
+}
+
+#[test]
+fn convert_to_bool_strict_rejects_coercion() {
+    let m = ConvertModule::new();
+    assert_eq!(
+        m.call("to_bool_strict", vec![n(0.0)]).unwrap(),
+        s("expected bool, got number").err()
+    );
+    assert_eq!(
+        m.call("to_bool_strict", vec![s("true")]).unwrap(),
+        s("expected bool, got string").err()
+    );
+    assert_eq!(
+        m.call("to_bool_strict", vec![Value::Nil]).unwrap(),
+        s("expected bool, got nil").err()
+    );
+}
+
+#[test]
+fn convert_parse_percent_valid() {
+    let m = ConvertModule::new();
+    let parse = |v: &str| unwrap_ok(m.call("parse_percent", vec![s(v)]).unwrap());
+    assert_eq!(parse("45%"), n(0.45));
+    assert_eq!(parse("100%"), n(1.0));
+    assert_eq!(parse("-12.5%"), n(-0.125));
+    assert_eq!(parse(" 7 % "), n(0.07));
+}
+
+#[test]
+fn convert_parse_percent_errors() {
+    let m = ConvertModule::new();
+    for bad in ["45", "%", "abc%", "4 5%", "+5%", "45%%"] {
+        let result = m.call("parse_percent", vec![s(bad)]).unwrap();
+        assert!(is_err(&result), "{bad:?} should be Err");
+    }
+    assert!(m.call("parse_percent", vec![n(45.0)]).is_err());
+}
+
+#[test]
+fn convert_to_percent_formats() {
+    let m = ConvertModule::new();
+    assert_eq!(
+        m.call("to_percent", vec![n(0.45), n(0.0)]).unwrap(),
+        s("45%")
+    );
+    assert_eq!(
+        m.call("to_percent", vec![n(0.4567), n(1.0)]).unwrap(),
+        s("45.7%")
+    );
+    assert_eq!(
+        m.call("to_percent", vec![n(-0.00001), n(1.0)]).unwrap(),
+        s("0.0%")
+    );
+    assert!(m.call("to_percent", vec![n(0.5), n(-1.0)]).is_err());
+}
+
+#[test]
+fn convert_to_percent_caps_decimals() {
+    let m = ConvertModule::new();
+    assert!(m.call("to_percent", vec![n(1.0), n(100.0)]).is_ok());
+    for decimals in [101.0, 70_000.0] {
+        let err = m.call("to_percent", vec![n(1.0), n(decimals)]).unwrap_err();
+        assert!(
+            matches!(err, StdlibError::RuntimeError(ref msg) if msg.contains("at most 100")),
+            "{err}"
+        );
+    }
+}
+
+#[test]
+fn convert_to_percent_rejects_non_finite() {
+    let m = ConvertModule::new();
+    for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, f64::MAX] {
+        let err = m.call("to_percent", vec![n(value), n(1.0)]).unwrap_err();
+        assert!(matches!(err, StdlibError::RuntimeError(_)), "{err}");
+    }
+}
+
+#[test]
+fn convert_percent_round_trip() {
+    let m = ConvertModule::new();
+    for fraction in [0.0, 0.45, 0.125, 1.5, -0.2] {
+        let text = m.call("to_percent", vec![n(fraction), n(3.0)]).unwrap();
+        let back = unwrap_ok(m.call("parse_percent", vec![text]).unwrap());
+        assert_eq!(back, n(fraction));
+    }
+}
+
+#[test]
+fn convert_coerce_to_number() {
+    let m = ConvertModule::new();
+    assert_eq!(
+        unwrap_ok(m.call("coerce", vec![s(" 42.5 "), s("number")]).unwrap()),
+        n(42.5)
+    );
+    assert_eq!(
+        unwrap_ok(m.call("coerce", vec![b(true), s("number")]).unwrap()),
+        n(1.0)
+    );
+}
+
+#[test]
+fn convert_coerce_to_bool_and_string() {
+    let m = ConvertModule::new();
+    assert_eq!(
+        unwrap_ok(m.call("coerce", vec![n(0.0), s("bool")]).unwrap()),
+        b(false)
+    );
+    assert_eq!(
+        unwrap_ok(m.call("coerce", vec![n(3.0), s("bool")]).unwrap()),
+        b(true)
+    );
+    assert_eq!(
+        unwrap_ok(m.call("coerce", vec![n(3.0), s("string")]).unwrap()),
+        s("3")
+    );
+}
+
+#[test]
+fn convert_coerce_errors() {
+    let m = ConvertModule::new();
+    let unsupported = m.call("coerce", vec![n(1.0), s("list")]).unwrap();
+    assert_eq!(unsupported, s("unsupported coercion target 'list'").err());
+    let impossible = m.call("coerce", vec![s("abc"), s("number")]).unwrap();
+    assert!(is_err(&impossible));
+    assert!(is_err(
+        &m.call("coerce", vec![Value::Nil, s("number")]).unwrap()
+    ));
+    assert!(m.call("coerce", vec![n(1.0), n(2.0)]).is_err());
+}
+
+#[test]
+fn convert_bytes_to_human_units() {
+    let m = ConvertModule::new();
+    let human = |bytes: f64| m.call("bytes_to_human", vec![n(bytes)]).unwrap();
+    assert_eq!(human(0.0), s("0 B"));
+    assert_eq!(human(1023.0), s("1023 B"));
+    assert_eq!(human(1536.0), s("1.5 KB"));
+    assert_eq!(human(1024.0 * 1024.0), s("1.0 MB"));
+    assert_eq!(human(1024.0 * 1024.0 - 1.0), s("1.0 MB"));
+    assert_eq!(human(5.0 * 1024.0 * 1024.0 * 1024.0), s("5.0 GB"));
+    assert_eq!(human(2048.0 * 1024f64.powi(4)), s("2048.0 TB"));
+}
+
+#[test]
+fn convert_bytes_to_human_rejects_invalid() {
+    let m = ConvertModule::new();
+    assert!(m.call("bytes_to_human", vec![n(-1.0)]).is_err());
+    assert!(m.call("bytes_to_human", vec![n(1.5)]).is_err());
+    assert!(m.call("bytes_to_human", vec![s("1024")]).is_err());
+}
+
+#[test]
+fn convert_infer_each_type() {
+    let m = ConvertModule::new();
+    let infer = |v: &str| m.call("infer", vec![s(v)]).unwrap();
+    assert_eq!(infer("null"), Value::Nil);
+    assert_eq!(infer("nil"), Value::Nil);
+    assert_eq!(infer("true"), b(true));
+    assert_eq!(infer("false"), b(false));
+    assert_eq!(infer("42"), n(42.0));
+    assert_eq!(infer("-3.5e2"), n(-350.0));
+    assert_eq!(infer(" 7 "), n(7.0));
+    assert_eq!(infer("hello"), s("hello"));
+}
+
+#[test]
+fn convert_infer_keeps_near_misses_as_strings() {
+    let m = ConvertModule::new();
+    let infer = |v: &str| m.call("infer", vec![s(v)]).unwrap();
+    assert_eq!(infer("42px"), s("42px"));
+    assert_eq!(infer("1.2.3"), s("1.2.3"));
+    assert_eq!(infer("NaN"), s("NaN"));
+    assert_eq!(infer("1e400"), s("1e400"));
+    assert_eq!(infer("True"), s("True"));
+}
+
+#[test]
+fn convert_infer_empty_string() {
+    let m = ConvertModule::new();
+    assert_eq!(m.call("infer", vec![s("")]).unwrap(), s(""));
+    assert_eq!(m.call("infer", vec![s("  ")]).unwrap(), s("  "));
+    assert!(m.call("infer", vec![n(1.0)]).is_err());
+}
+
+#[test]
+fn convert_wrong_arg_count() {
+    let m = ConvertModule::new();
+    assert!(m.call("to_string", vec![]).is_err());
+    assert!(m.call("to_number", vec![]).is_err());
+    assert!(m.call("parse_int", vec![]).is_err());
+    assert!(m.call("parse_float", vec![]).is_err());
+    assert!(m.call("to_bool", vec![]).is_err());
+    assert!(m.call("to_i32", vec![]).is_err());
+}
+
+#[test]
+fn convert_parse_int_wrong_type() {
+    let m = ConvertModule::new();
+    assert!(m.call("parse_int", vec![n(1.0)]).is_err());
+}
+
+#[test]
+fn convert_has_function() {
+    let m = ConvertModule::new();
+    assert!(m.has_function("to_string"));
+    assert!(m.has_function("to_number"));
+    assert!(m.has_function("parse_int"));
+    assert!(m.has_function("parse_float"));
+    assert!(m.has_function("to_bool"));
+    assert!(m.has_function("to_i32"));
+    assert!(m.has_function("format_number"));
+    assert!(m.has_function("to_number_strict"));
+    assert!(m.has_function("to_bool_strict"));
+    assert!(m.has_function("parse_percent"));
+    assert!(m.has_function("to_percent"));
+    assert!(m.has_function("coerce"));
+    assert!(m.has_function("bytes_to_human"));
+    assert!(m.has_function("infer"));
+    assert!(!m.has_function("cast"));
+    assert_eq!(m.name(), "convert");
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// json module
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn json_parse_object() {
+    let m = JsonModule::new();
+    let result = m
+        .call("parse", vec![s(r#"{"a": 1, "b": "hello"}"#)])
+        .unwrap();
+    assert!(is_ok(&result));
+    let val = unwrap_ok(result);
+    match &val {
+        Value::Record { fields, .. } => {
+            assert_eq!(fields.get("a"), Some(&n(1.0)));
+            assert_eq!(fields.get("b"), Some(&s("hello")));
+        }
+        _ => panic!("expected record, got {:?}", val),
+    }
+}
+
+#[test]
+fn json_parse_array() {
+    let m = JsonModule::new();
+    let result = m.call("parse", vec![s("[1, 2, 3]")]).unwrap();
+    let val = unwrap_ok(result);
+    assert_eq!(val, Value::List(vec![n(1.0), n(2.0), n(3.0)]));
+}
+
+#[test]
+fn json_parse_primitives() {
     let m = JsonModule::new();
     assert_eq!(unwrap_ok(m.call("parse", vec![s("42")]).unwrap()), n(42.0));
     assert_eq!(
@@ -552,11 +1377,336 @@ fn json_roundtrip() {
     assert_eq!(original, roundtripped);
 }
 
+#[test]
+fn json_pluck_uniform_list() {
+    let m = JsonModule::new();
+    let items = Value::List(vec![
+        rec(vec![("id", n(1.0)), ("name", s("a"))]),
+        rec(vec![("id", n(2.0)), ("name", s("b"))]),
+    ]);
+    assert_eq!(
+        m.call("pluck", vec![items, s("name")]).unwrap(),
+        Value::List(vec![s("a"), s("b")])
+    );
+}
+
+#[test]
+fn json_pluck_missing_key_is_nil() {
+    let m = JsonModule::new();
+    let items = Value::List(vec![rec(vec![("id", n(1.0))]), rec(vec![("name", s("b"))])]);
+    assert_eq!(
+        m.call("pluck", vec![items, s("id")]).unwrap(),
+        Value::List(vec![n(1.0), Value::Nil])
+    );
+}
+
+#[test]
+fn json_pluck_non_list_or_non_record() {
+    let m = JsonModule::new();
+    let single = rec(vec![("id", n(1.0))]);
+    assert!(m.call("pluck", vec![single, s("id")]).is_err());
+    let mixed = Value::List(vec![rec(vec![("id", n(1.0))]), n(2.0)]);
+    assert!(m.call("pluck", vec![mixed, s("id")]).is_err());
+}
+
+#[test]
+fn json_parse_array_and_object_kinds() {
+    let m = JsonModule::new();
+    let list = m.call("parse_array", vec![s("[1, 2]")]).unwrap();
+    assert_eq!(unwrap_ok(list), Value::List(vec![n(1.0), n(2.0)]));
+    let obj = m.call("parse_object", vec![s(r#"{"a": true}"#)]).unwrap();
+    assert_eq!(unwrap_ok(obj), rec(vec![("a", b(true))]));
+}
+
+#[test]
+fn json_parse_array_rejects_other_kinds() {
+    let m = JsonModule::new();
+    let result = m.call("parse_array", vec![s("42")]).unwrap();
+    assert!(is_err(&result));
+    assert_eq!(
+        result,
+        Value::String("expected JSON array, got number".into()).err()
+    );
+    assert!(is_err(&m.call("parse_array", vec![s("{}")]).unwrap()));
+    assert!(is_err(&m.call("parse_object", vec![s("[]")]).unwrap()));
+    assert!(is_err(&m.call("parse_object", vec![s("null")]).unwrap()));
+}
+
+#[test]
+fn json_parse_array_invalid_json() {
+    let m = JsonModule::new();
+    assert!(is_err(&m.call("parse_array", vec![s("[1,")]).unwrap()));
+    assert!(is_err(&m.call("parse_object", vec![s("{oops}")]).unwrap()));
+}
+
+#[test]
+fn json_diff_record_fields() {
+    let m = JsonModule::new();
+    let old = rec(vec![("a", n(1.0)), ("b", n(2.0)), ("c", s("x"))]);
+    let new = rec(vec![("a", n(1.0)), ("b", n(3.0)), ("d", b(true))]);
+    let diff = m.call("diff", vec![old, new]).unwrap();
+    assert_eq!(
+        diff,
+        rec(vec![
+            ("equal", b(false)),
+            ("added", rec(vec![("d", b(true))])),
+            ("removed", rec(vec![("c", s("x"))])),
+            (
+                "changed",
+                rec(vec![("b", rec(vec![("old", n(2.0)), ("new", n(3.0))]))])
+            ),
+        ])
+    );
+}
+
+#[test]
+fn json_diff_identical_is_empty() {
+    let m = JsonModule::new();
+    let doc = rec(vec![("a", n(1.0)), ("tags", Value::List(vec![s("x")]))]);
+    let diff = m.call("diff", vec![doc.clone(), doc]).unwrap();
+    assert_eq!(
+        diff,
+        rec(vec![
+            ("equal", b(true)),
+            ("added", rec(vec![])),
+            ("removed", rec(vec![])),
+            ("changed", rec(vec![])),
+        ])
+    );
+}
+
+#[test]
+fn json_diff_is_shallow() {
+    let m = JsonModule::new();
+    let old = rec(vec![("inner", rec(vec![("x", n(1.0))]))]);
+    let new = rec(vec![("inner", rec(vec![("x", n(2.0))]))]);
+    let diff = m.call("diff", vec![old.clone(), new.clone()]).unwrap();
+    let changed = diff.as_record().unwrap()["changed"].clone();
+    assert_eq!(
+        changed,
+        rec(vec![(
+            "inner",
+            rec(vec![
+                ("old", rec(vec![("x", n(1.0))])),
+                ("new", rec(vec![("x", n(2.0))])),
+            ])
+        )])
+    );
+}
+
+#[test]
+fn json_diff_scalars_and_lists() {
+    let m = JsonModule::new();
+    let equal_of = |a: Value, c: Value| {
+        let diff = m.call("diff", vec![a, c]).unwrap();
+        diff.as_record().unwrap()["equal"].clone()
+    };
+    assert_eq!(equal_of(n(1.0), n(1.0)), b(true));
+    assert_eq!(equal_of(n(1.0), s("1")), b(false));
+    let list = Value::List(vec![n(1.0), n(2.0)]);
+    assert_eq!(equal_of(list.clone(), list.clone()), b(true));
+    assert_eq!(equal_of(list, Value::List(vec![n(2.0)])), b(false));
+    // Record vs non-record: unequal, no field-level changes
+    let diff = m
+        .call("diff", vec![rec(vec![("a", n(1.0))]), Value::Nil])
+        .unwrap();
+    assert_eq!(diff.as_record().unwrap()["removed"], rec(vec![]));
+}
+
+#[test]
+fn json_stringify_canonical_equal_records_match() {
+    let m = JsonModule::new();
+    let a = rec(vec![("zeta", n(1.0)), ("alpha", s("x")), ("mid", b(true))]);
+    let b_rec = rec(vec![("mid", b(true)), ("alpha", s("x")), ("zeta", n(1.0))]);
+    let out_a = m.call("stringify_canonical", vec![a]).unwrap();
+    let out_b = m.call("stringify_canonical", vec![b_rec]).unwrap();
+    assert_eq!(out_a, out_b);
+    assert_eq!(out_a, s(r#"{"alpha":"x","mid":true,"zeta":1.0}"#));
+}
+
+#[test]
+fn json_stringify_canonical_sorts_nested() {
+    let m = JsonModule::new();
+    let inner = rec(vec![("b", n(2.0)), ("a", n(1.0))]);
+    let value = rec(vec![
+        ("outer", Value::List(vec![inner.clone()])),
+        ("inner", inner),
+    ]);
+    assert_eq!(
+        m.call("stringify_canonical", vec![value]).unwrap(),
+        s(r#"{"inner":{"a":1.0,"b":2.0},"outer":[{"a":1.0,"b":2.0}]}"#)
+    );
+}
+
+#[test]
+fn json_stringify_canonical_folds_negative_zero() {
+    let m = JsonModule::new();
+    let pos = rec(vec![("x", n(0.0)), ("xs", Value::List(vec![n(0.0)]))]);
+    let neg = rec(vec![("x", n(-0.0)), ("xs", Value::List(vec![n(-0.0)]))]);
+    assert_eq!(pos, neg);
+    let out_pos = m.call("stringify_canonical", vec![pos]).unwrap();
+    assert_eq!(m.call("stringify_canonical", vec![neg]).unwrap(), out_pos);
+    assert_eq!(out_pos, s(r#"{"x":0.0,"xs":[0.0]}"#));
+}
+
+#[test]
+fn json_flatten_two_levels() {
+    let m = JsonModule::new();
+    let value = rec(vec![
+        (
+            "user",
+            rec(vec![
+                ("address", rec(vec![("city", s("Oslo"))])),
+                ("name", s("Ada")),
+            ]),
+        ),
+        ("active", b(true)),
+    ]);
+    assert_eq!(
+        m.call("flatten", vec![value]).unwrap(),
+        rec(vec![
+            ("user.address.city", s("Oslo")),
+            ("user.name", s("Ada")),
+            ("active", b(true)),
+        ])
+    );
+}
+
+#[test]
+fn json_flatten_nested_array() {
+    let m = JsonModule::new();
+    let value = rec(vec![(
+        "items",
+        Value::List(vec![n(1.0), rec(vec![("id", n(2.0))])]),
+    )]);
+    assert_eq!(
+        m.call("flatten", vec![value]).unwrap(),
+        rec(vec![("items.0", n(1.0)), ("items.1.id", n(2.0))])
+    );
+}
+
+#[test]
+fn json_flatten_already_flat_and_empty_containers() {
+    let m = JsonModule::new();
+    let flat = rec(vec![("a", n(1.0)), ("b", s("x"))]);
+    assert_eq!(m.call("flatten", vec![flat.clone()]).unwrap(), flat);
+    let empties = rec(vec![("list", Value::List(vec![])), ("obj", rec(vec![]))]);
+    assert_eq!(m.call("flatten", vec![empties.clone()]).unwrap(), empties);
+}
+
+#[test]
+fn json_flatten_depth_guard() {
+    let m = JsonModule::new();
+    let mut value = n(1.0);
+    for _ in 0..40 {
+        value = rec(vec![("k", value)]);
+    }
+    assert!(m.call("flatten", vec![value]).is_err());
+    assert!(m.call("flatten", vec![n(1.0)]).is_err());
+}
+
+#[test]
+fn json_unflatten_round_trip() {
+    let m = JsonModule::new();
+    let value = rec(vec![
+        (
+            "user",
+            rec(vec![
+                ("address", rec(vec![("city", s("Oslo"))])),
+                ("name", s("Ada")),
+            ]),
+        ),
+        ("tags", Value::List(vec![s("a"), rec(vec![("id", n(2.0))])])),
+        ("empty", Value::List(vec![])),
+    ]);
+    let flat = m.call("flatten", vec![value.clone()]).unwrap();
+    assert_eq!(m.call("unflatten", vec![flat]).unwrap(), value);
+}
+
+#[test]
+fn json_unflatten_rebuilds_arrays() {
+    let m = JsonModule::new();
+    let flat = rec(vec![
+        ("xs.1", s("b")),
+        ("xs.0", s("a")),
+        ("gap.0", n(1.0)),
+        ("gap.2", n(3.0)),
+    ]);
+    assert_eq!(
+        m.call("unflatten", vec![flat]).unwrap(),
+        rec(vec![
+            ("xs", Value::List(vec![s("a"), s("b")])),
+            // Non-contiguous indices stay a record
+            ("gap", rec(vec![("0", n(1.0)), ("2", n(3.0))])),
+        ])
+    );
+}
+
+#[test]
+fn json_unflatten_conflicting_paths() {
+    let m = JsonModule::new();
+    let flat = rec(vec![("a", n(1.0)), ("a.b", n(2.0))]);
+    let err = m.call("unflatten", vec![flat]).unwrap_err();
+    assert!(err.to_string().contains("conflicts"), "{err}");
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn json_value_conversions_round_trip() {
+    let json = serde_json::json!({
+        "name": "Ada",
+        "tags": ["x", 1.5, true, null],
+        "nested": { "depth": 2.5 }
+    });
+    let value = Value::try_from(json.clone()).unwrap();
+    assert_eq!(
+        value,
+        rec(vec![
+            ("name", s("Ada")),
+            ("nested", rec(vec![("depth", n(2.5))])),
+            (
+                "tags",
+                Value::List(vec![s("x"), n(1.5), b(true), Value::Nil])
+            ),
+        ])
+    );
+    assert_eq!(serde_json::Value::from(&value), json);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn json_value_conversions_primitives() {
+    for json in [
+        serde_json::json!(null),
+        serde_json::json!(false),
+        serde_json::json!(-3.5),
+        serde_json::json!("hi"),
+        serde_json::json!([]),
+    ] {
+        let value = Value::try_from(json.clone()).unwrap();
+        assert_eq!(serde_json::Value::from(&value), json);
+    }
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn json_value_conversion_depth_limit() {
+    let mut json = serde_json::json!(1);
+    for _ in 0..40 {
+        json = serde_json::json!([json]);
+    }
+    assert!(matches!(
+        Value::try_from(json),
+        Err(StdlibError::RuntimeError(msg)) if msg.contains("depth")
+    ));
+}
+
 #[test]
 fn json_wrong_arg_count() {
     let m = JsonModule::new();
     assert!(m.call("parse", vec![]).is_err());
     assert!(m.call("stringify", vec![]).is_err());
+    assert!(m.call("pluck", vec![Value::List(vec![])]).is_err());
 }
 
 #[test]
@@ -570,6 +1720,13 @@ fn json_has_function() {
     let m = JsonModule::new();
     assert!(m.has_function("parse"));
     assert!(m.has_function("stringify"));
+    assert!(m.has_function("pluck"));
+    assert!(m.has_function("parse_array"));
+    assert!(m.has_function("parse_object"));
+    assert!(m.has_function("diff"));
+    assert!(m.has_function("stringify_canonical"));
+    assert!(m.has_function("flatten"));
+    assert!(m.has_function("unflatten"));
     assert!(!m.has_function("decode"));
     assert_eq!(m.name(), "json");
 }
@@ -0,0 +1,151 @@
+//! Tests for `StdlibRegistry` and per-function arity metadata.
+
+use pepl_stdlib::modules::core::CoreModule;
+use pepl_stdlib::{Arity, StdlibError, StdlibModule, StdlibRegistry, Value};
+
+/// A module whose functions must never run — proves validation is static.
+struct TrapModule;
+
+impl StdlibModule for TrapModule {
+    fn name(&self) -> &'static str {
+        "trap"
+    }
+
+    fn has_function(&self, function: &str) -> bool {
+        matches!(function, "fire" | "loose")
+    }
+
+    fn call(&self, function: &str, _args: Vec<Value>) -> Result<Value, StdlibError> {
+        panic!("trap.{function} was executed during validation");
+    }
+
+    fn arity(&self, function: &str) -> Option<Arity> {
+        match function {
+            "fire" => Some(Arity::Exact(1)),
+            _ => None,
+        }
+    }
+}
+
+// ── Arity ────────────────────────────────────────────────────────────────────
+
+#[test]
+fn arity_accepts() {
+    assert!(Arity::Exact(2).accepts(2));
+    assert!(!Arity::Exact(2).accepts(1));
+    assert!(Arity::Range(1, 2).accepts(1));
+    assert!(Arity::Range(1, 2).accepts(2));
+    assert!(!Arity::Range(1, 2).accepts(3));
+    assert!(Arity::AtLeast(0).accepts(0));
+    assert!(Arity::AtLeast(1).accepts(9));
+    assert!(!Arity::AtLeast(1).accepts(0));
+}
+
+#[test]
+fn builtin_modules_declare_arity() {
+    let registry = StdlibRegistry::new();
+    let math = registry.get("math").unwrap();
+    assert_eq!(math.arity("abs"), Some(Arity::Exact(1)));
+    assert_eq!(math.arity("nope"), None);
+    let list = registry.get("list").unwrap();
+    assert_eq!(list.arity("map"), Some(Arity::Exact(2)));
+    assert_eq!(list.arity("of"), Some(Arity::AtLeast(0)));
+    assert_eq!(list.arity("sort"), Some(Arity::Range(1, 2)));
+    assert_eq!(list.arity("collect_results"), Some(Arity::Exact(1)));
+    assert_eq!(list.arity("partition_results"), Some(Arity::Exact(1)));
+}
+
+// ── validate_call ────────────────────────────────────────────────────────────
+
+#[test]
+fn validate_call_rejects_wrong_arity() {
+    let err = StdlibRegistry::new()
+        .validate_call("math", "abs", 2)
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        StdlibError::WrongArgCount { ref function, expected: 1, got: 2 } if function == "math.abs"
+    ));
+}
+
+#[test]
+fn validate_call_accepts_higher_order_call() {
+    assert!(StdlibRegistry::new()
+        .validate_call("list", "map", 2)
+        .is_ok());
+}
+
+#[test]
+fn validate_call_ranges_and_variadics() {
+    let registry = StdlibRegistry::new();
+    assert!(registry.validate_call("core", "assert", 1).is_ok());
+    assert!(registry.validate_call("core", "assert", 2).is_ok());
+    assert!(matches!(
+        registry.validate_call("core", "assert", 3),
+        Err(StdlibError::WrongArgCount { expected: 2, .. })
+    ));
+    assert!(matches!(
+        registry.validate_call("core", "assert", 0),
+        Err(StdlibError::WrongArgCount { expected: 1, .. })
+    ));
+    assert!(registry.validate_call("list", "of", 0).is_ok());
+    assert!(registry.validate_call("list", "of", 6).is_ok());
+}
+
+#[test]
+fn validate_call_unknown_names() {
+    let registry = StdlibRegistry::new();
+    assert!(matches!(
+        registry.validate_call("math", "nope", 1),
+        Err(StdlibError::UnknownFunction { .. })
+    ));
+    assert!(matches!(
+        registry.validate_call("nope", "abs", 1),
+        Err(StdlibError::UnknownFunction { .. })
+    ));
+}
+
+#[test]
+fn validate_call_capability_modules() {
+    let registry = StdlibRegistry::new();
+    assert!(registry.validate_call("http", "get", 1).is_ok());
+    assert!(registry.validate_call("storage", "get", 2).is_err());
+}
+
+#[test]
+fn validate_call_does_not_execute() {
+    let mut registry = StdlibRegistry::empty();
+    registry.register(TrapModule);
+    assert!(registry.validate_call("trap", "fire", 1).is_ok());
+    assert!(registry.validate_call("trap", "fire", 0).is_err());
+    // No declared arity: only existence is checked
+    assert!(registry.validate_call("trap", "loose", 5).is_ok());
+}
+
+// ── Registration & dispatch ──────────────────────────────────────────────────
+
+#[test]
+fn registry_call_dispatches() {
+    let registry = StdlibRegistry::new();
+    assert_eq!(
+        registry
+            .call("math", "abs", vec![Value::Number(-2.0)])
+            .unwrap(),
+        Value::Number(2.0)
+    );
+    assert!(matches!(
+        registry.call("nope", "abs", vec![]),
+        Err(StdlibError::UnknownFunction { .. })
+    ));
+}
+
+#[test]
+fn register_replaces_same_name() {
+    let mut registry = StdlibRegistry::new();
+    registry.register(CoreModule::with_capabilities(&["http"]));
+    let result = registry
+        .call("core", "capability", vec![Value::String("http".into())])
+        .unwrap();
+    assert_eq!(result, Value::Bool(true));
+    assert!(StdlibRegistry::empty().get("core").is_none());
+}
@@ -1,5 +1,7 @@
 //! Integration tests for `pepl-stdlib` Phase 3: string module.
 
+#![allow(clippy::approx_constant)] // 3.14 etc. are arbitrary decimals, not PI
+
 use pepl_stdlib::modules::string::StringModule;
 use pepl_stdlib::{StdlibError, StdlibModule, Value};
 use std::collections::BTreeMap;
@@ -23,7 +25,7 @@ fn call(func: &str, args: Vec<Value>) -> Result<Value, StdlibError> {
 }
 
 fn call_ok(func: &str, args: Vec<Value>) -> Value {
-    call(func, args).expect(&format!("string.{func} should succeed"))
+    call(func, args).unwrap_or_else(|e| panic!("string.{func} should succeed: {e}"))
 }
 
 fn expect_str(func: &str, args: Vec<Value>) -> String {
@@ -80,6 +82,18 @@ fn test_has_function_known() {
         "from",
         "is_empty",
         "index_of",
+        "normalize_whitespace",
+        "count_words",
+        "split_n",
+        "char_indices",
+        "interleave",
+        "strip_prefix",
+        "strip_suffix",
+        "pad_number",
+        "is_blank",
+        "indent",
+        "common_prefix",
+        "common_suffix",
     ] {
         assert!(m.has_function(f), "string should have function {f}");
     }
@@ -817,6 +831,415 @@ fn test_index_of_unicode() {
     assert_eq!(expect_num("index_of", vec![s("café"), s("é")]), 3.0);
 }
 
+// ══════════════════════════════════════════════════════════════════════════════
+// string.normalize_whitespace
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_normalize_whitespace_trims_ends() {
+    assert_eq!(
+        expect_str("normalize_whitespace", vec![s("  hello world \n")]),
+        "hello world"
+    );
+}
+
+#[test]
+fn test_normalize_whitespace_collapses_internal_runs() {
+    assert_eq!(
+        expect_str("normalize_whitespace", vec![s("a \t b\n\n c   d")]),
+        "a b c d"
+    );
+}
+
+#[test]
+fn test_normalize_whitespace_all_whitespace() {
+    assert_eq!(expect_str("normalize_whitespace", vec![s(" \t\r\n ")]), "");
+}
+
+#[test]
+fn test_normalize_whitespace_already_normal() {
+    assert_eq!(
+        expect_str("normalize_whitespace", vec![s("one two")]),
+        "one two"
+    );
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// string.count_words
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_count_words_sentence() {
+    assert_eq!(
+        expect_num("count_words", vec![s("the quick brown fox")]),
+        4.0
+    );
+}
+
+#[test]
+fn test_count_words_extra_whitespace() {
+    assert_eq!(
+        expect_num("count_words", vec![s("  hello   world \t\n again ")]),
+        3.0
+    );
+}
+
+#[test]
+fn test_count_words_empty() {
+    assert_eq!(expect_num("count_words", vec![s("")]), 0.0);
+    assert_eq!(expect_num("count_words", vec![s(" \t\n ")]), 0.0);
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// string.split_n
+// ══════════════════════════════════════════════════════════════════════════════
+
+fn strs(items: &[&str]) -> Value {
+    Value::List(items.iter().map(|v| s(v)).collect())
+}
+
+#[test]
+fn test_split_n_limit_one() {
+    assert_eq!(
+        call_ok("split_n", vec![s("a,b,c"), s(","), num(1.0)]),
+        strs(&["a,b,c"])
+    );
+}
+
+#[test]
+fn test_split_n_limit_two() {
+    assert_eq!(
+        call_ok("split_n", vec![s("a,b,c"), s(","), num(2.0)]),
+        strs(&["a", "b,c"])
+    );
+}
+
+#[test]
+fn test_split_n_limit_exceeds_occurrences() {
+    assert_eq!(
+        call_ok("split_n", vec![s("a,b,c"), s(","), num(10.0)]),
+        strs(&["a", "b", "c"])
+    );
+}
+
+#[test]
+fn test_split_n_empty_delimiter() {
+    assert_eq!(
+        call_ok("split_n", vec![s("abcd"), s(""), num(3.0)]),
+        strs(&["a", "b", "cd"])
+    );
+}
+
+#[test]
+fn test_split_n_invalid_limit() {
+    for limit in [0.0, -1.0, 1.5] {
+        let err = call("split_n", vec![s("a,b"), s(","), num(limit)]).unwrap_err();
+        assert!(matches!(err, StdlibError::RuntimeError(_)));
+    }
+    let err = call("split_n", vec![s("a,b"), s(","), s("2")]).unwrap_err();
+    assert!(matches!(err, StdlibError::TypeMismatch { position: 3, .. }));
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// string.char_indices
+// ══════════════════════════════════════════════════════════════════════════════
+
+fn char_at(c: &str, byte_index: f64, char_index: f64) -> Value {
+    Value::record_of(&[
+        ("char", s(c)),
+        ("byte_index", num(byte_index)),
+        ("char_index", num(char_index)),
+    ])
+}
+
+#[test]
+fn test_char_indices_ascii() {
+    assert_eq!(
+        call_ok("char_indices", vec![s("ab")]),
+        Value::List(vec![char_at("a", 0.0, 0.0), char_at("b", 1.0, 1.0)])
+    );
+}
+
+#[test]
+fn test_char_indices_multibyte() {
+    // 'é' is 2 bytes, '😀' is 4 bytes in UTF-8
+    assert_eq!(
+        call_ok("char_indices", vec![s("aé😀z")]),
+        Value::List(vec![
+            char_at("a", 0.0, 0.0),
+            char_at("é", 1.0, 1.0),
+            char_at("😀", 3.0, 2.0),
+            char_at("z", 7.0, 3.0),
+        ])
+    );
+}
+
+#[test]
+fn test_char_indices_empty() {
+    assert_eq!(call_ok("char_indices", vec![s("")]), Value::List(vec![]));
+}
+
+#[test]
+fn test_char_indices_wrong_type() {
+    assert!(call("char_indices", vec![num(1.0)]).is_err());
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// string.interleave
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_interleave_equal_lengths() {
+    assert_eq!(expect_str("interleave", vec![s("abc"), s("123")]), "a1b2c3");
+}
+
+#[test]
+fn test_interleave_longer_first() {
+    assert_eq!(
+        expect_str("interleave", vec![s("abcde"), s("12")]),
+        "a1b2cde"
+    );
+}
+
+#[test]
+fn test_interleave_longer_second() {
+    assert_eq!(expect_str("interleave", vec![s("ab"), s("1234")]), "a1b234");
+}
+
+#[test]
+fn test_interleave_empty_inputs() {
+    assert_eq!(expect_str("interleave", vec![s(""), s("xy")]), "xy");
+    assert_eq!(expect_str("interleave", vec![s("xy"), s("")]), "xy");
+    assert_eq!(expect_str("interleave", vec![s(""), s("")]), "");
+}
+
+#[test]
+fn test_interleave_unicode() {
+    assert_eq!(expect_str("interleave", vec![s("é😀"), s("ab")]), "éa😀b");
+}
+
+#[test]
+fn test_interleave_wrong_type() {
+    let err = call("interleave", vec![s("a"), num(1.0)]).unwrap_err();
+    assert!(matches!(err, StdlibError::TypeMismatch { position: 2, .. }));
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// string.strip_prefix / string.strip_suffix
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_strip_prefix_present_and_absent() {
+    assert_eq!(
+        expect_str("strip_prefix", vec![s("v1.2.3"), s("v")]),
+        "1.2.3"
+    );
+    assert_eq!(
+        expect_str("strip_prefix", vec![s("1.2.3"), s("v")]),
+        "1.2.3"
+    );
+    // Only one occurrence is removed
+    assert_eq!(expect_str("strip_prefix", vec![s("aab"), s("a")]), "ab");
+}
+
+#[test]
+fn test_strip_suffix_present_and_absent() {
+    assert_eq!(
+        expect_str("strip_suffix", vec![s("report.pdf"), s(".pdf")]),
+        "report"
+    );
+    assert_eq!(
+        expect_str("strip_suffix", vec![s("report.txt"), s(".pdf")]),
+        "report.txt"
+    );
+}
+
+#[test]
+fn test_strip_affix_empty_is_unchanged() {
+    assert_eq!(expect_str("strip_prefix", vec![s("abc"), s("")]), "abc");
+    assert_eq!(expect_str("strip_suffix", vec![s("abc"), s("")]), "abc");
+}
+
+#[test]
+fn test_strip_affix_whole_string() {
+    assert_eq!(expect_str("strip_prefix", vec![s("abc"), s("abc")]), "");
+    assert_eq!(expect_str("strip_suffix", vec![s("abc"), s("abc")]), "");
+    // Affix longer than the string: unchanged
+    assert_eq!(expect_str("strip_suffix", vec![s("bc"), s("abc")]), "bc");
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// string.pad_number
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_pad_number_positive_and_negative() {
+    assert_eq!(
+        expect_str("pad_number", vec![num(42.0), num(6.0)]),
+        "    42"
+    );
+    assert_eq!(
+        expect_str("pad_number", vec![num(-3.5), num(6.0)]),
+        "  -3.5"
+    );
+}
+
+#[test]
+fn test_pad_number_never_truncates() {
+    assert_eq!(
+        expect_str("pad_number", vec![num(123456.0), num(3.0)]),
+        "123456"
+    );
+    assert_eq!(expect_str("pad_number", vec![num(7.0), num(0.0)]), "7");
+}
+
+#[test]
+fn test_pad_number_invalid_width() {
+    assert!(call("pad_number", vec![num(1.0), num(-1.0)]).is_err());
+    assert!(call("pad_number", vec![num(1.0), num(2.5)]).is_err());
+    assert!(call("pad_number", vec![s("1"), num(2.0)]).is_err());
+}
+
+#[test]
+fn test_pad_number_large_width() {
+    let padded = expect_str("pad_number", vec![num(1.0), num(70_000.0)]);
+    assert_eq!(padded.len(), 70_000);
+    assert!(padded.ends_with(" 1"));
+    assert_eq!(padded.trim_start(), "1");
+}
+
+#[test]
+fn test_pad_number_width_limit() {
+    let padded = expect_str("pad_number", vec![num(1.0), num(1_000_000.0)]);
+    assert_eq!(padded.len(), 1_000_000);
+    for width in [1_000_001.0, 1e12, 1e19] {
+        assert!(matches!(
+            call("pad_number", vec![num(1.0), num(width)]),
+            Err(StdlibError::RuntimeError(msg)) if msg.contains("at most")
+        ));
+    }
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// string.is_blank
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_is_blank_vs_is_empty() {
+    for (input, blank, empty) in [
+        ("", true, true),
+        ("   ", true, false),
+        ("\t\n", true, false),
+        (" a ", false, false),
+    ] {
+        assert_eq!(expect_bool("is_blank", vec![s(input)]), blank, "{input:?}");
+        assert_eq!(expect_bool("is_empty", vec![s(input)]), empty, "{input:?}");
+    }
+}
+
+#[test]
+fn test_is_blank_wrong_type() {
+    assert!(call("is_blank", vec![num(1.0)]).is_err());
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// string.indent
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_indent_multi_line() {
+    assert_eq!(
+        expect_str("indent", vec![s("a:\n  b: 1\nc: 2"), s("  ")]),
+        "  a:\n    b: 1\n  c: 2"
+    );
+}
+
+#[test]
+fn test_indent_single_line() {
+    assert_eq!(expect_str("indent", vec![s("hello"), s("> ")]), "> hello");
+    assert_eq!(expect_str("indent", vec![s(""), s("> ")]), "");
+}
+
+#[test]
+fn test_indent_preserves_line_endings() {
+    assert_eq!(expect_str("indent", vec![s("a\n"), s("\t")]), "\ta\n");
+    assert_eq!(
+        expect_str("indent", vec![s("a\r\nb\r\n"), s("- ")]),
+        "- a\r\n- b\r\n"
+    );
+}
+
+#[test]
+fn test_indent_skips_empty_lines() {
+    assert_eq!(
+        expect_str("indent", vec![s("a\n\nb\n"), s("  ")]),
+        "  a\n\n  b\n"
+    );
+    assert_eq!(expect_str("indent", vec![s("a\n \n"), s("#")]), "#a\n# \n");
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// string.common_prefix / string.common_suffix
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_common_prefix() {
+    assert_eq!(
+        expect_str("common_prefix", vec![s("interstellar"), s("internet")]),
+        "inter"
+    );
+    assert_eq!(expect_str("common_prefix", vec![s("abc"), s("xyz")]), "");
+    assert_eq!(expect_str("common_prefix", vec![s(""), s("abc")]), "");
+}
+
+#[test]
+fn test_common_suffix() {
+    assert_eq!(
+        expect_str("common_suffix", vec![s("running"), s("jumping")]),
+        "ing"
+    );
+    assert_eq!(expect_str("common_suffix", vec![s("abc"), s("xyz")]), "");
+}
+
+#[test]
+fn test_common_affix_fully_shared() {
+    assert_eq!(
+        expect_str("common_prefix", vec![s("same"), s("same")]),
+        "same"
+    );
+    assert_eq!(
+        expect_str("common_suffix", vec![s("same"), s("same")]),
+        "same"
+    );
+    // One string contained in the other
+    assert_eq!(
+        expect_str("common_prefix", vec![s("pre"), s("prefix")]),
+        "pre"
+    );
+    assert_eq!(
+        expect_str("common_suffix", vec![s("fix"), s("suffix")]),
+        "fix"
+    );
+}
+
+#[test]
+fn test_common_affix_multibyte_boundary() {
+    // "é" and "è" share their first UTF-8 byte but are different characters
+    assert_eq!(
+        expect_str("common_prefix", vec![s("café"), s("cafè")]),
+        "caf"
+    );
+    assert_eq!(expect_str("common_suffix", vec![s("éa"), s("èa")]), "a");
+    assert_eq!(
+        expect_str("common_prefix", vec![s("日本語"), s("日本人")]),
+        "日本"
+    );
+    assert_eq!(
+        expect_str("common_suffix", vec![s("🎉 ok"), s("👍 ok")]),
+        " ok"
+    );
+}
+
 // ══════════════════════════════════════════════════════════════════════════════
 // Unicode / multi-byte edge cases
 // ══════════════════════════════════════════════════════════════════════════════
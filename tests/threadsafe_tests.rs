@@ -0,0 +1,47 @@
+//! Tests for the `threadsafe` feature: values and stdlib calls shared across
+//! worker threads.
+
+#![cfg(feature = "threadsafe")]
+
+use std::thread;
+
+use pepl_stdlib::modules::list::ListModule;
+use pepl_stdlib::{SharedValue, StdlibFn, StdlibModule, Value};
+
+fn double() -> Value {
+    Value::Function(StdlibFn::new(|args| match &args[0] {
+        Value::Number(n) => Ok(Value::Number(n * 2.0)),
+        other => Ok(other.clone()),
+    }))
+}
+
+#[test]
+fn concurrent_list_map() {
+    let items: SharedValue =
+        Value::List((0..100).map(|n| Value::Number(n as f64)).collect()).into_shared();
+    let f: SharedValue = double().into_shared();
+
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            let items = SharedValue::clone(&items);
+            let f = SharedValue::clone(&f);
+            thread::spawn(move || {
+                ListModule::new()
+                    .call("map", vec![(*items).clone(), (*f).clone()])
+                    .unwrap()
+            })
+        })
+        .collect();
+
+    let expected = Value::List((0..100).map(|n| Value::Number(n as f64 * 2.0)).collect());
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), expected);
+    }
+}
+
+#[test]
+fn values_move_between_threads() {
+    let record = Value::record_of(&[("name", Value::from("Ada")), ("id", Value::from(1i64))]);
+    let back = thread::spawn(move || record).join().unwrap();
+    assert_eq!(back.type_name(), "record");
+}
@@ -0,0 +1,171 @@
+//! Tests for the `validate` stdlib module.
+
+use pepl_stdlib::modules::validate::ValidateModule;
+use pepl_stdlib::{StdlibError, StdlibModule, Value};
+
+// ── Helpers ──────────────────────────────────────────────────────────────────
+
+fn call(func: &str, args: Vec<Value>) -> Result<Value, StdlibError> {
+    ValidateModule::new().call(func, args)
+}
+
+fn check(func: &str, args: Vec<Value>) -> bool {
+    match call(func, args) {
+        Ok(Value::Bool(b)) => b,
+        other => panic!("validate.{func}: expected bool, got {other:?}"),
+    }
+}
+
+fn s(v: &str) -> Value {
+    Value::String(v.to_string())
+}
+
+fn n(v: f64) -> Value {
+    Value::Number(v)
+}
+
+// ── validate.is_email ────────────────────────────────────────────────────────
+
+#[test]
+fn is_email_valid() {
+    for email in ["ada@example.com", "a.b+tag@mail.example.org", "x@y.io"] {
+        assert!(check("is_email", vec![s(email)]), "{email}");
+    }
+}
+
+#[test]
+fn is_email_invalid() {
+    for email in [
+        "",
+        "ada",
+        "@example.com",
+        "ada@",
+        "ada@localhost",
+        "ada@example..com",
+        "ada@example.com.",
+        "a@b@example.com",
+        "ada lovelace@example.com",
+    ] {
+        assert!(!check("is_email", vec![s(email)]), "{email}");
+    }
+}
+
+// ── validate.is_url ──────────────────────────────────────────────────────────
+
+#[test]
+fn is_url_valid() {
+    for url in [
+        "http://example.com",
+        "https://example.com/path?q=1#frag",
+        "HTTPS://example.com:8080",
+    ] {
+        assert!(check("is_url", vec![s(url)]), "{url}");
+    }
+}
+
+#[test]
+fn is_url_invalid() {
+    for url in [
+        "",
+        "example.com",
+        "ftp://example.com",
+        "https://",
+        "https:///path",
+        "https://exa mple.com",
+    ] {
+        assert!(!check("is_url", vec![s(url)]), "{url}");
+    }
+}
+
+// ── validate.is_numeric ──────────────────────────────────────────────────────
+
+#[test]
+fn is_numeric_cases() {
+    assert!(check("is_numeric", vec![s("0123")]));
+    assert!(!check("is_numeric", vec![s("")]));
+    assert!(!check("is_numeric", vec![s("-1")]));
+    assert!(!check("is_numeric", vec![s("1.5")]));
+    assert!(!check("is_numeric", vec![s(" 1")]));
+    assert!(!check("is_numeric", vec![s("١٢")])); // non-ASCII digits
+}
+
+// ── validate.is_alpha ────────────────────────────────────────────────────────
+
+#[test]
+fn is_alpha_cases() {
+    assert!(check("is_alpha", vec![s("Hello")]));
+    assert!(check("is_alpha", vec![s("Zoë")]));
+    assert!(!check("is_alpha", vec![s("")]));
+    assert!(!check("is_alpha", vec![s("abc1")]));
+    assert!(!check("is_alpha", vec![s("two words")]));
+}
+
+// ── validate.in_range ────────────────────────────────────────────────────────
+
+#[test]
+fn in_range_inclusive() {
+    assert!(check("in_range", vec![n(5.0), n(1.0), n(10.0)]));
+    assert!(check("in_range", vec![n(1.0), n(1.0), n(10.0)]));
+    assert!(check("in_range", vec![n(10.0), n(1.0), n(10.0)]));
+    assert!(!check("in_range", vec![n(0.0), n(1.0), n(10.0)]));
+    assert!(!check("in_range", vec![n(10.5), n(1.0), n(10.0)]));
+    assert!(!check("in_range", vec![n(f64::NAN), n(1.0), n(10.0)]));
+}
+
+// ── validate.length_between ──────────────────────────────────────────────────
+
+#[test]
+fn length_between_counts_chars() {
+    assert!(check("length_between", vec![s("abc"), n(1.0), n(3.0)]));
+    assert!(!check("length_between", vec![s("abcd"), n(1.0), n(3.0)]));
+    assert!(!check("length_between", vec![s(""), n(1.0), n(3.0)]));
+    // "héllo" is 5 chars but 6 bytes
+    assert!(check("length_between", vec![s("héllo"), n(5.0), n(5.0)]));
+}
+
+// ── Module ───────────────────────────────────────────────────────────────────
+
+#[test]
+fn validate_has_function() {
+    let m = ValidateModule::new();
+    assert_eq!(m.name(), "validate");
+    for f in [
+        "is_email",
+        "is_url",
+        "is_numeric",
+        "is_alpha",
+        "in_range",
+        "length_between",
+    ] {
+        assert!(m.has_function(f), "validate should have function {f}");
+    }
+    assert!(!m.has_function("is_phone"));
+}
+
+#[test]
+fn validate_wrong_args() {
+    assert!(matches!(
+        call("is_email", vec![]),
+        Err(StdlibError::WrongArgCount { .. })
+    ));
+    assert!(matches!(
+        call("in_range", vec![n(1.0), n(2.0)]),
+        Err(StdlibError::WrongArgCount { .. })
+    ));
+    assert!(matches!(
+        call("is_url", vec![n(1.0)]),
+        Err(StdlibError::TypeMismatch { position: 1, .. })
+    ));
+    assert!(matches!(
+        call("length_between", vec![s("a"), s("1"), n(2.0)]),
+        Err(StdlibError::TypeMismatch { position: 2, .. })
+    ));
+}
+
+#[test]
+fn validate_unknown_function() {
+    assert!(matches!(
+        call("nope", vec![]),
+        Err(StdlibError::UnknownFunction { .. })
+    ));
+}